@@ -36,6 +36,8 @@ pub fn creation_benchmark(c: &mut Criterion) {
     fn run_all(mut group: BenchmarkGroup<WallTime>, sizes: &[usize]) {
         run_single::<spade_crate::SpadeCrate>(&mut group, sizes, "spade 2");
         run_single::<spade_crate::SpadeCrateWithHierarchy>(&mut group, sizes, "spade 2 hierarchy");
+        #[cfg(feature = "parallel")]
+        run_single::<spade_crate::SpadeCrateParallel>(&mut group, sizes, "spade 2 parallel");
         run_single::<cdt_crate::CdtCrate>(&mut group, sizes, "cdt");
         run_single::<delaunator_crate::DelaunatorCrate>(&mut group, sizes, "delaunator");
         group.finish();
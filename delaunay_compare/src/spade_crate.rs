@@ -41,3 +41,23 @@ where
         Self::ResultType::bulk_load(self.vertices.clone()).unwrap()
     }
 }
+
+/// Uses parallel bulk loading (requires the `parallel` feature)
+#[cfg(feature = "parallel")]
+#[derive(Default)]
+pub struct SpadeCrateParallel {
+    vertices: Vec<SpadePoint>,
+}
+
+#[cfg(feature = "parallel")]
+impl crate::DelaunayCrate for SpadeCrateParallel {
+    type ResultType = spade::DelaunayTriangulation<SpadePoint>;
+
+    fn init(&mut self, vertices: impl IntoIterator<Item = [f64; 2]>) {
+        self.vertices = vertices.into_iter().map(|vertex| vertex.into()).collect()
+    }
+
+    fn run_creation(&self) -> Self::ResultType {
+        Self::ResultType::bulk_load_parallel(self.vertices.clone()).unwrap()
+    }
+}
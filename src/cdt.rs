@@ -1,9 +1,10 @@
 use crate::{delaunay_core::Dcel, intersection_iterator::LineIntersectionIterator};
 use crate::{handles::*, intersection_iterator::Intersection};
 use crate::{
-    DelaunayTriangulation, HasPosition, HintGenerator, InsertionError, LastUsedVertexHintGenerator,
-    Point2, Triangulation, TriangulationExt,
+    DelaunayTriangulation, HasPosition, HasPositionMut, HintGenerator, InsertionError,
+    LastUsedVertexHintGenerator, Point2, Triangulation, TriangulationExt,
 };
+use num_traits::Float;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -17,25 +18,53 @@ use alloc::{vec, vec::Vec};
 /// This type will only be relevant if the triangulation's undirected edge type is being
 /// overwritten.
 ///
+/// Constraint edges also store the id of the constraint they belong to, see
+/// [ConstrainedDelaunayTriangulation::add_constraint_with_id].
+///
 /// # Type parameters
 /// UE: The user configurable undirected edge type.
+///
+/// C: The constraint id type. The default `()` does not take up any space.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(
     feature = "serde",
     derive(Serialize, Deserialize),
     serde(crate = "serde")
 )]
-pub struct CdtEdge<UE>(bool, UE);
-
-impl<UE> CdtEdge<UE> {
+pub struct CdtEdge<UE, C = ()>(
+    bool,
+    UE,
+    // Defaults to `C::default()` to support deserializing CDTs that were serialized before
+    // constraint ids were introduced.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, bound(deserialize = "C: Deserialize<'de> + Default"))
+    )]
+    C,
+);
+
+impl<UE, C> CdtEdge<UE, C> {
     /// Returns `true` if this edge is a constraint edge.
     pub fn is_constraint_edge(&self) -> bool {
         self.0
     }
 
-    fn make_constraint_edge(&mut self) {
+    /// Returns the id of the constraint this edge belongs to.
+    ///
+    /// Returns `None` if this edge is no constraint edge. Constraints that were added without an
+    /// id store `C::default()`.
+    pub fn constraint_id(&self) -> Option<&C> {
+        if self.is_constraint_edge() {
+            Some(&self.2)
+        } else {
+            None
+        }
+    }
+
+    fn make_constraint_edge(&mut self, id: C) {
         assert!(!self.is_constraint_edge());
         self.0 = true;
+        self.2 = id;
     }
 
     /// Returns the wrapped undirected edge data type.
@@ -49,24 +78,86 @@ impl<UE> CdtEdge<UE> {
     }
 }
 
-impl<UE: Default> Default for CdtEdge<UE> {
+impl<UE, C: Default> CdtEdge<UE, C> {
+    fn unmake_constraint_edge(&mut self) {
+        assert!(self.is_constraint_edge());
+        self.0 = false;
+        self.2 = C::default();
+    }
+}
+
+impl<UE: Default, C: Default> Default for CdtEdge<UE, C> {
     fn default() -> Self {
-        CdtEdge(false, UE::default())
+        CdtEdge(false, UE::default(), C::default())
     }
 }
 
-impl<UE> AsRef<UE> for CdtEdge<UE> {
+impl<UE, C> AsRef<UE> for CdtEdge<UE, C> {
     fn as_ref(&self) -> &UE {
         self.data()
     }
 }
 
-impl<UE> AsMut<UE> for CdtEdge<UE> {
+impl<UE, C> AsMut<UE> for CdtEdge<UE, C> {
     fn as_mut(&mut self) -> &mut UE {
         self.data_mut()
     }
 }
 
+/// The error type returned by [ConstrainedDelaunayTriangulation::bulk_load_cdt].
+#[derive(Copy, Clone, PartialOrd, Ord, PartialEq, Eq, Debug, Hash)]
+pub enum CdtBulkLoadError {
+    /// An input vertex could not be inserted. See [InsertionError] for more details.
+    InsertionError(InsertionError),
+
+    /// An input edge intersects another input edge.
+    ///
+    /// Contains the index of one of the intersecting input edges.
+    IntersectingConstraintEdges(usize),
+}
+
+impl From<InsertionError> for CdtBulkLoadError {
+    fn from(error: InsertionError) -> Self {
+        CdtBulkLoadError::InsertionError(error)
+    }
+}
+
+impl core::fmt::Display for CdtBulkLoadError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        <Self as core::fmt::Debug>::fmt(self, f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CdtBulkLoadError {}
+
+/// Determines which faces are considered to be inside a set of polygon rings.
+///
+/// Used by [ConstrainedDelaunayTriangulation::classify_inner_faces_with_rings] and
+/// [ConstrainedDelaunayTriangulation::bulk_load_polygons].
+#[derive(Copy, Clone, PartialOrd, Ord, PartialEq, Eq, Debug, Hash)]
+pub enum FillRule {
+    /// A face is inside if any ray starting in it crosses the rings an odd number of times.
+    ///
+    /// The orientation of the rings is irrelevant. A ring nested inside another ring forms a hole.
+    EvenOdd,
+
+    /// A face is inside if the rings wind around it at least once, i.e. if its
+    /// [winding number](https://en.wikipedia.org/wiki/Winding_number) is not zero.
+    ///
+    /// Holes need to be oriented opposite to their surrounding ring.
+    NonZero,
+}
+
+impl FillRule {
+    fn is_inside(self, winding_number: i32) -> bool {
+        match self {
+            FillRule::EvenOdd => winding_number % 2 != 0,
+            FillRule::NonZero => winding_number != 0,
+        }
+    }
+}
+
 /// A two dimensional
 /// [constrained Delaunay triangulation](https://en.wikipedia.org/wiki/Constrained_Delaunay_triangulation).
 ///
@@ -92,6 +183,9 @@ impl<UE> AsMut<UE> for CdtEdge<UE> {
 /// the usual Delaunay triangulation, refer to `DelaunayTriangulation`
 /// for more information about type parameters, iteration, performance
 /// and more examples.
+///
+/// The additional type parameter `C` defines the type of constraint ids, see
+/// [add_constraint_with_id](ConstrainedDelaunayTriangulation::add_constraint_with_id).
 
 ///
 /// # Example
@@ -137,25 +231,28 @@ pub struct ConstrainedDelaunayTriangulation<
     UE = (),
     F = (),
     L = LastUsedVertexHintGenerator,
+    C = (),
 > where
     V: HasPosition,
     DE: Default,
     UE: Default,
     F: Default,
     L: HintGenerator<<V as HasPosition>::Scalar>,
+    C: Default + Clone,
 {
-    s: Dcel<V, DE, CdtEdge<UE>, F>,
+    s: Dcel<V, DE, CdtEdge<UE, C>, F>,
     num_constraints: usize,
     lookup: L,
 }
 
-impl<V, DE, UE, F, L> Default for ConstrainedDelaunayTriangulation<V, DE, UE, F, L>
+impl<V, DE, UE, F, L, C> Default for ConstrainedDelaunayTriangulation<V, DE, UE, F, L, C>
 where
     V: HasPosition,
     DE: Default,
     UE: Default,
     F: Default,
     L: HintGenerator<<V as HasPosition>::Scalar>,
+    C: Default + Clone,
 {
     fn default() -> Self {
         ConstrainedDelaunayTriangulation {
@@ -166,25 +263,26 @@ where
     }
 }
 
-impl<V, DE, UE, F, L> Triangulation for ConstrainedDelaunayTriangulation<V, DE, UE, F, L>
+impl<V, DE, UE, F, L, C> Triangulation for ConstrainedDelaunayTriangulation<V, DE, UE, F, L, C>
 where
     V: HasPosition,
     DE: Default,
     UE: Default,
     F: Default,
     L: HintGenerator<<V as HasPosition>::Scalar>,
+    C: Default + Clone,
 {
     type Vertex = V;
     type DirectedEdge = DE;
-    type UndirectedEdge = CdtEdge<UE>;
+    type UndirectedEdge = CdtEdge<UE, C>;
     type Face = F;
     type HintGenerator = L;
 
-    fn s(&self) -> &Dcel<V, DE, CdtEdge<UE>, F> {
+    fn s(&self) -> &Dcel<V, DE, CdtEdge<UE, C>, F> {
         &self.s
     }
 
-    fn s_mut(&mut self) -> &mut Dcel<V, DE, CdtEdge<UE>, F> {
+    fn s_mut(&mut self) -> &mut Dcel<V, DE, CdtEdge<UE, C>, F> {
         &mut self.s
     }
 
@@ -194,11 +292,21 @@ where
 
     fn handle_legal_edge_split(&mut self, handles: [FixedDirectedEdgeHandle; 2]) {
         self.num_constraints += 1;
+        // One of the two parts is the original constraint edge. The other part inherits its id.
+        let id = handles
+            .iter()
+            .find_map(|e| {
+                self.s
+                    .undirected_edge_data(e.as_undirected())
+                    .constraint_id()
+            })
+            .cloned()
+            .unwrap_or_default();
         for handle in handles.iter().map(|e| e.as_undirected()) {
             if !self.is_constraint_edge(handle) {
                 self.s
                     .undirected_edge_data_mut(handle)
-                    .make_constraint_edge();
+                    .make_constraint_edge(id.clone());
             }
         }
     }
@@ -219,18 +327,19 @@ where
     }
 }
 
-impl<V, DE, UE, F, L> From<DelaunayTriangulation<V, DE, UE, F, L>>
-    for ConstrainedDelaunayTriangulation<V, DE, UE, F, L>
+impl<V, DE, UE, F, L, C> From<DelaunayTriangulation<V, DE, UE, F, L>>
+    for ConstrainedDelaunayTriangulation<V, DE, UE, F, L, C>
 where
     V: HasPosition,
     DE: Default,
     UE: Default,
     F: Default,
     L: HintGenerator<<V as HasPosition>::Scalar>,
+    C: Default + Clone,
 {
     fn from(value: DelaunayTriangulation<V, DE, UE, F, L>) -> Self {
         let dcel = value.dcel;
-        let s = dcel.map_undirected_edges(|edge| CdtEdge(false, edge));
+        let s = dcel.map_undirected_edges(|edge| CdtEdge(false, edge, C::default()));
         let lookup = value.hint_generator;
 
         ConstrainedDelaunayTriangulation {
@@ -241,13 +350,14 @@ where
     }
 }
 
-impl<V, DE, UE, F, L> ConstrainedDelaunayTriangulation<V, DE, UE, F, L>
+impl<V, DE, UE, F, L, C> ConstrainedDelaunayTriangulation<V, DE, UE, F, L, C>
 where
     V: HasPosition,
     DE: Default,
     UE: Default,
     F: Default,
     L: HintGenerator<<V as HasPosition>::Scalar>,
+    C: Default + Clone,
 {
     /// Removes a vertex from the triangulation.
     ///
@@ -257,15 +367,81 @@ where
     /// # Handle invalidation
     /// This method will invalidate all vertex, edge and face handles.
     pub fn remove(&mut self, vertex: FixedVertexHandle) -> V {
-        let num_removed_constraints = self
+        self.unmake_adjacent_constraint_edges(vertex);
+        self.remove_and_notify(vertex)
+    }
+
+    /// Removes all constraint edges adjacent to a vertex that is about to be removed.
+    ///
+    /// Removing a vertex of the convex hull flips some of its adjacent edges. These edges must not
+    /// remain marked as constraint edges after they have been flipped.
+    fn unmake_adjacent_constraint_edges(&mut self, vertex: FixedVertexHandle) {
+        let constraint_edges: Vec<_> = self
             .s
             .vertex(vertex)
             .out_edges()
-            .map(|edge| edge.is_constraint_edge())
-            .filter(|b| *b)
-            .count();
-        self.num_constraints -= num_removed_constraints;
-        self.remove_and_notify(vertex)
+            .filter(|edge| edge.is_constraint_edge())
+            .map(|edge| edge.fix().as_undirected())
+            .collect();
+
+        for edge in constraint_edges {
+            self.remove_constraint(edge);
+        }
+    }
+
+    /// Moves a vertex to a new position.
+    ///
+    /// Works like [Triangulation::move_vertex]. Any constraint edge adjacent to the vertex is
+    /// moved along with it.
+    ///
+    /// # Panics
+    /// Panics if any moved constraint edge would intersect another constraint edge.
+    ///
+    /// # Handle invalidation
+    /// This method will invalidate all edge and face handles. Vertex handles remain valid.
+    pub fn move_vertex(
+        &mut self,
+        vertex: FixedVertexHandle,
+        new_position: Point2<V::Scalar>,
+    ) -> Result<bool, InsertionError>
+    where
+        V: HasPositionMut,
+    {
+        let constraint_neighbors: Vec<_> = self
+            .s
+            .vertex(vertex)
+            .out_edges()
+            .filter_map(|edge| Some((edge.to().fix(), edge.constraint_id()?.clone())))
+            .collect();
+
+        if constraint_neighbors.is_empty() {
+            return self.move_vertex_and_notify(vertex, new_position);
+        }
+
+        crate::validate_coordinate(new_position.x)?;
+        crate::validate_coordinate(new_position.y)?;
+
+        let old_position = self.vertex(vertex).position();
+        if old_position == new_position {
+            return Ok(true);
+        }
+
+        if !self.move_vertex_within_star(vertex, new_position) {
+            if self.locate_vertex(new_position).is_some() {
+                return Ok(false);
+            }
+
+            // Removing the vertex also removes its constraint edges. They are restored afterwards.
+            self.unmake_adjacent_constraint_edges(vertex);
+            self.move_vertex_by_reinsertion(vertex, new_position);
+            for (neighbor, id) in constraint_neighbors {
+                self.add_constraint_core(vertex, neighbor, id);
+            }
+        }
+
+        self.hint_generator_mut()
+            .notify_vertex_moved(vertex, old_position, new_position);
+        Ok(true)
     }
 
     /// Returns the number of constraint edges.
@@ -285,6 +461,60 @@ where
             .unwrap_or(false)
     }
 
+    /// Returns the chain of constraint edges that connects two vertices along a straight line.
+    ///
+    /// [exists_constraint](Self::exists_constraint) only checks for a single edge. However, a
+    /// constraint is split into several collinear constraint edges whenever a vertex is inserted
+    /// on it, e.g. during [refinement](Self::refine). This method walks along the line from `from`
+    /// to `to` and returns all directed edges of that line, ordered from `from` to `to`.
+    ///
+    /// Returns `None` if any part of the line is not covered by a constraint edge. Returns an
+    /// empty chain if `from` and `to` are equal.
+    ///
+    /// # Example
+    /// ```
+    /// # fn main() -> Result<(), spade::InsertionError> {
+    /// use spade::{ConstrainedDelaunayTriangulation, Point2, Triangulation};
+    /// let mut cdt = ConstrainedDelaunayTriangulation::<Point2<f64>>::new();
+    /// let v0 = cdt.insert(Point2::new(0.0, 0.0))?;
+    /// let v1 = cdt.insert(Point2::new(2.0, 0.0))?;
+    /// cdt.insert(Point2::new(1.0, 1.0))?;
+    /// cdt.add_constraint(v0, v1);
+    ///
+    /// // Splits the constraint edge into two
+    /// let v2 = cdt.insert(Point2::new(1.0, 0.0))?;
+    /// assert!(!cdt.exists_constraint(v0, v1));
+    ///
+    /// let chain = cdt.constraint_chain(v0, v1).unwrap();
+    /// assert_eq!(chain.len(), 2);
+    /// assert_eq!(cdt.directed_edge(chain[0]).to().fix(), v2);
+    /// # Ok(()) }
+    /// ```
+    pub fn constraint_chain(
+        &self,
+        from: FixedVertexHandle,
+        to: FixedVertexHandle,
+    ) -> Option<Vec<FixedDirectedEdgeHandle>> {
+        let mut chain = Vec::new();
+        let mut current = from;
+        for intersection in LineIntersectionIterator::new_from_handles(self, from, to) {
+            match intersection {
+                Intersection::VertexIntersection(_) => {}
+                Intersection::EdgeOverlap(edge) if edge.is_constraint_edge() => {
+                    chain.push(edge.fix());
+                    current = edge.to().fix();
+                }
+                _ => return None,
+            }
+        }
+
+        if current == to {
+            Some(chain)
+        } else {
+            None
+        }
+    }
+
     /// Checks if a constraint edge can be added.
     ///
     /// Returns `false` if the line from `from` to `to` intersects another
@@ -309,7 +539,7 @@ where
 
     fn contains_any_constraint_edge(
         &self,
-        mut line_intersection_iterator: LineIntersectionIterator<V, DE, CdtEdge<UE>, F>,
+        mut line_intersection_iterator: LineIntersectionIterator<V, DE, CdtEdge<UE, C>, F>,
     ) -> bool {
         line_intersection_iterator.any(|intersection| match intersection {
             Intersection::EdgeIntersection(edge) => edge.is_constraint_edge(),
@@ -352,6 +582,31 @@ where
         &mut self,
         vertices: impl IntoIterator<Item = V>,
         closed: bool,
+    ) -> Result<(), InsertionError> {
+        self.add_constraint_edges_core(vertices, closed, C::default())
+    }
+
+    /// Creates several constraint edges and assigns the same id to all of them.
+    ///
+    /// Works like [add_constraint_edges](Self::add_constraint_edges). Refer to
+    /// [add_constraint_with_id](Self::add_constraint_with_id) for more information about constraint ids.
+    ///
+    /// # Panics
+    /// Panics if any of the generated constraints intersects with any other constraint edge.
+    pub fn add_constraint_edges_with_id(
+        &mut self,
+        vertices: impl IntoIterator<Item = V>,
+        closed: bool,
+        id: C,
+    ) -> Result<(), InsertionError> {
+        self.add_constraint_edges_core(vertices, closed, id)
+    }
+
+    fn add_constraint_edges_core(
+        &mut self,
+        vertices: impl IntoIterator<Item = V>,
+        closed: bool,
+        id: C,
     ) -> Result<(), InsertionError> {
         let mut iter = vertices.into_iter();
         if let Some(first) = iter.next() {
@@ -360,12 +615,12 @@ where
             let mut current_handle = first_handle;
             for current in iter {
                 current_handle = self.insert(current)?;
-                self.add_constraint(previous_handle, current_handle);
+                self.add_constraint_core(previous_handle, current_handle, id.clone());
                 previous_handle = current_handle;
             }
 
             if closed && current_handle != first_handle {
-                self.add_constraint(current_handle, first_handle);
+                self.add_constraint_core(current_handle, first_handle, id);
             }
         }
 
@@ -397,15 +652,289 @@ where
     ///
     /// # Panics
     /// Panics if the new constraint edge intersects an existing
-    /// constraint edge.
+    /// constraint edge. Use [add_constraint_and_split](Self::add_constraint_and_split)
+    /// to split intersecting constraint edges instead.
     pub fn add_constraint(&mut self, from: FixedVertexHandle, to: FixedVertexHandle) -> bool {
+        self.add_constraint_core(from, to, C::default())
+    }
+
+    /// Adds a constraint edge between two vertices and assigns an id to it.
+    ///
+    /// Works like [add_constraint](Self::add_constraint). All constraint edges created by this call
+    /// will store `id`, see [CdtEdge::constraint_id]. The id type is given by the type parameter
+    /// `C`. Constraints added without an id store `C::default()`, consider using an `Option` if
+    /// these need to be distinguished. The id is kept if a constraint edge is split
+    /// into smaller parts, e.g. by inserting a vertex on it or by
+    /// [refinement](Self::refine). This allows to identify which input constraint (for example, which
+    /// polyline) any constraint edge belongs to. The meaning of the id is up to the caller, e.g. it
+    /// could be an index into a list of polylines.
+    ///
+    /// Parts of the constraint that overlap an already existing constraint edge will keep the
+    /// existing edge's id.
+    ///
+    /// # Example
+    /// ```
+    /// use spade::{
+    ///     ConstrainedDelaunayTriangulation, LastUsedVertexHintGenerator, Point2, Triangulation,
+    /// };
+    /// # fn try_main() -> Result<(), spade::InsertionError> {
+    /// type Cdt = ConstrainedDelaunayTriangulation<
+    ///     Point2<f64>,
+    ///     (),
+    ///     (),
+    ///     (),
+    ///     LastUsedVertexHintGenerator,
+    ///     u32, // Constraint id type
+    /// >;
+    ///
+    /// let mut cdt = Cdt::new();
+    /// let v0 = cdt.insert(Point2::new(0.0, 0.0))?;
+    /// let v1 = cdt.insert(Point2::new(2.0, 0.0))?;
+    /// cdt.add_constraint_with_id(v0, v1, 7);
+    ///
+    /// // Splits the constraint edge into two parts
+    /// let v2 = cdt.insert(Point2::new(1.0, 0.0))?;
+    ///
+    /// for edge in cdt.vertex(v2).out_edges() {
+    ///     assert_eq!(edge.constraint_id(), Some(&7));
+    /// }
+    /// # Ok(()) }
+    /// # fn main() { try_main().unwrap() }
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the new constraint edge intersects an existing constraint edge.
+    pub fn add_constraint_with_id(
+        &mut self,
+        from: FixedVertexHandle,
+        to: FixedVertexHandle,
+        id: C,
+    ) -> bool {
+        self.add_constraint_core(from, to, id)
+    }
+
+    fn add_constraint_core(
+        &mut self,
+        from: FixedVertexHandle,
+        to: FixedVertexHandle,
+        id: C,
+    ) -> bool {
+        match self.try_add_constraint_core(from, to, id) {
+            Ok(result) => result,
+            Err(_) => panic!("Error - constraint edges must not intersect each other"),
+        }
+    }
+
+    /// Creates a new CDT from a set of vertices and constraint edges.
+    ///
+    /// Each constraint edge is given by the indices of its two vertices within `vertices`.
+    /// The vertices are loaded with the same algorithm as [Triangulation::bulk_load]. Constraint
+    /// edges are added during the same pass as soon as both of their vertices have been inserted.
+    ///
+    /// Duplicated vertex positions are merged into a single vertex. An edge referring to any of
+    /// these duplicates will be connected to the merged vertex.
+    ///
+    /// Returns an error if any vertex could not be inserted or if any two constraint edges intersect.
+    /// Constraint edges are still allowed to share an end point or to overlap.
+    ///
+    /// # Example
+    /// ```
+    /// use spade::{ConstrainedDelaunayTriangulation, Point2, Triangulation};
+    /// # fn try_main() -> Result<(), spade::CdtBulkLoadError> {
+    /// let vertices = vec![
+    ///     Point2::new(0.0, 0.0),
+    ///     Point2::new(1.0, 0.0),
+    ///     Point2::new(1.0, 1.0),
+    ///     Point2::new(0.0, 1.0),
+    /// ];
+    /// let edges = vec![[0, 1], [1, 2], [2, 3], [3, 0]];
+    ///
+    /// let cdt = ConstrainedDelaunayTriangulation::<Point2<f64>>::bulk_load_cdt(vertices, edges)?;
+    /// assert_eq!(cdt.num_vertices(), 4);
+    /// assert_eq!(cdt.num_constraints(), 4);
+    /// # Ok(()) }
+    /// # fn main() { try_main().unwrap() }
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if any edge refers to a vertex index that is out of bounds.
+    pub fn bulk_load_cdt(
+        vertices: Vec<V>,
+        edges: Vec<[usize; 2]>,
+    ) -> Result<Self, CdtBulkLoadError> {
+        Self::bulk_load_cdt_with_vertex_handles(vertices, edges).map(|(result, _)| result)
+    }
+
+    /// Creates a new CDT that contains a set of polygons.
+    ///
+    /// Each polygon ring is given by its vertices. Rings are always closed, the last vertex is
+    /// connected to the first vertex. A polygon with holes is described by its outer ring and one
+    /// ring per hole. The rings are loaded with [bulk_load_cdt](Self::bulk_load_cdt), afterwards
+    /// all inner faces are classified with
+    /// [classify_inner_faces_with_rings](Self::classify_inner_faces_with_rings).
+    ///
+    /// Returns the CDT and all of its inner faces that lie inside the polygons. Returns an error if
+    /// any vertex could not be inserted or if any two ring edges intersect. The index contained in
+    /// [CdtBulkLoadError::IntersectingConstraintEdges] counts the edges of all rings in order.
+    ///
+    /// # Example
+    /// ```
+    /// use spade::{ConstrainedDelaunayTriangulation, FillRule, Point2, Triangulation};
+    /// # fn try_main() -> Result<(), spade::CdtBulkLoadError> {
+    /// let outer = vec![
+    ///     Point2::new(0.0, 0.0),
+    ///     Point2::new(3.0, 0.0),
+    ///     Point2::new(3.0, 3.0),
+    ///     Point2::new(0.0, 3.0),
+    /// ];
+    /// let hole = vec![
+    ///     Point2::new(1.0, 1.0),
+    ///     Point2::new(2.0, 1.0),
+    ///     Point2::new(2.0, 2.0),
+    ///     Point2::new(1.0, 2.0),
+    /// ];
+    ///
+    /// let (cdt, interior_faces) = ConstrainedDelaunayTriangulation::<Point2<f64>>::bulk_load_polygons(
+    ///     vec![outer, hole],
+    ///     FillRule::EvenOdd,
+    /// )?;
+    ///
+    /// // The two faces of the hole are excluded
+    /// assert_eq!(cdt.num_inner_faces(), 10);
+    /// assert_eq!(interior_faces.len(), 8);
+    /// # Ok(()) }
+    /// # fn main() { try_main().unwrap() }
+    /// ```
+    pub fn bulk_load_polygons(
+        rings: Vec<Vec<V>>,
+        fill_rule: FillRule,
+    ) -> Result<(Self, Vec<FixedFaceHandle<InnerTag>>), CdtBulkLoadError> {
+        let mut vertices = Vec::new();
+        let mut edges = Vec::new();
+        let mut ring_ranges = Vec::with_capacity(rings.len());
+        for ring in rings {
+            let start = vertices.len();
+            let len = ring.len();
+            vertices.extend(ring);
+            edges.extend((0..len).map(|index| [start + index, start + (index + 1) % len]));
+            ring_ranges.push(start..start + len);
+        }
+
+        let (result, handles) = Self::bulk_load_cdt_with_vertex_handles(vertices, edges)?;
+
+        let rings: Vec<Vec<_>> = ring_ranges
+            .into_iter()
+            .map(|range| handles[range].to_vec())
+            .collect();
+        let is_inside = result.classify_inner_faces_with_rings(&rings, fill_rule);
+        let interior_faces = result
+            .fixed_inner_faces()
+            .filter(|face| is_inside[face.index()])
+            .collect();
+
+        Ok((result, interior_faces))
+    }
+
+    fn bulk_load_cdt_with_vertex_handles(
+        vertices: Vec<V>,
+        edges: Vec<[usize; 2]>,
+    ) -> Result<(Self, Vec<FixedVertexHandle>), CdtBulkLoadError> {
+        let num_vertices = vertices.len();
+        assert!(
+            edges.iter().flatten().all(|index| *index < num_vertices),
+            "Constraint edge refers to a vertex index that is out of bounds"
+        );
+
+        // The adjacent edges of vertex i are stored in adjacent_edges[offsets[i]..offsets[i + 1]]
+        let mut offsets = vec![0; num_vertices + 1];
+        for index in edges.iter().flatten() {
+            offsets[*index + 1] += 1;
+        }
+        for i in 0..num_vertices {
+            offsets[i + 1] += offsets[i];
+        }
+        let mut adjacent_edges = vec![0; offsets[num_vertices]];
+        let mut next_slot = offsets.clone();
+        for (edge_index, edge) in edges.iter().enumerate() {
+            for index in edge {
+                adjacent_edges[next_slot[*index]] = edge_index;
+                next_slot[*index] += 1;
+            }
+        }
+
+        let mut inserted = vec![None; num_vertices];
+        let mut deferred_edges = Vec::new();
+
+        // Constraint edges are added as soon as both of their vertices have been inserted. The
+        // hull is not convex during the sweep: Any edge that would leave the triangulation is
+        // deferred until all vertices have been loaded.
+        let (mut result, handles) = crate::delaunay_core::bulk_load_with_callback(
+            vertices,
+            |cdt: &mut Self, index, handle| -> Result<(), CdtBulkLoadError> {
+                inserted[index] = Some(handle);
+                for edge_index in &adjacent_edges[offsets[index]..offsets[index + 1]] {
+                    let [from, to] = edges[*edge_index];
+                    let (Some(from), Some(to)) = (inserted[from], inserted[to]) else {
+                        continue;
+                    };
+
+                    if cdt.is_reachable_within_hull(from, to) {
+                        cdt.try_add_constraint_core(from, to, C::default())
+                            .map_err(|_| {
+                                CdtBulkLoadError::IntersectingConstraintEdges(*edge_index)
+                            })?;
+                    } else {
+                        deferred_edges.push(*edge_index);
+                    }
+                }
+                Ok(())
+            },
+        )?;
+
+        for edge_index in deferred_edges {
+            let [from, to] = edges[edge_index];
+            if result
+                .try_add_constraint_core(handles[from], handles[to], C::default())
+                .is_err()
+            {
+                return Err(CdtBulkLoadError::IntersectingConstraintEdges(edge_index));
+            }
+        }
+
+        let hint_generator = L::initialize_from_triangulation(&result);
+        result.lookup = hint_generator;
+        Ok((result, handles))
+    }
+
+    /// Returns `true` if the line between two vertices does not leave the triangulation.
+    ///
+    /// Used during bulk loading where the hull is not yet convex.
+    fn is_reachable_within_hull(&self, from: FixedVertexHandle, to: FixedVertexHandle) -> bool {
+        if self.all_vertices_on_line() {
+            return false;
+        }
+
+        let last = LineIntersectionIterator::new_from_handles(self, from, to).last();
+        matches!(last, Some(Intersection::VertexIntersection(vertex)) if vertex.fix() == to)
+    }
+
+    /// Adds a constraint edge or returns the first existing constraint edge that would be intersected.
+    ///
+    /// The triangulation will remain a valid CDT if an error is returned. However, some parts of the
+    /// new constraint may have been added already.
+    fn try_add_constraint_core(
+        &mut self,
+        from: FixedVertexHandle,
+        to: FixedVertexHandle,
+        id: C,
+    ) -> Result<bool, FixedUndirectedEdgeHandle> {
         use super::intersection_iterator::{
             trace_direction_out_of_edge, trace_direction_out_of_vertex, EdgeOutDirection,
             VertexOutDirection,
         };
 
         if from == to {
-            return false;
+            return Ok(false);
         }
 
         let mut cur_from = from;
@@ -415,7 +944,7 @@ where
 
         'conflict_regions_loop: loop {
             if cur_from == to {
-                return result;
+                return Ok(result);
             }
 
             let first_intersection =
@@ -427,7 +956,7 @@ where
                 VertexOutDirection::EdgeOverlap(edge) => {
                     cur_from = edge.to().fix();
                     let edge = edge.fix().as_undirected();
-                    result |= self.make_constraint_edge(edge);
+                    result |= self.make_constraint_edge(edge, id.clone());
                     continue;
                 }
                 VertexOutDirection::EdgeIntersection(edge) => edge,
@@ -448,13 +977,14 @@ where
                 vec![first_edge.as_undirected().fix()];
 
             let check_for_constraint_intersection = |edge| {
-                assert!(
-                    !self.is_constraint_edge(edge),
-                    "Error - constraint edges must not intersect each other"
-                );
+                if self.is_constraint_edge(edge) {
+                    Err(edge)
+                } else {
+                    Ok(())
+                }
             };
 
-            check_for_constraint_intersection(current_edge.as_undirected().fix());
+            check_for_constraint_intersection(current_edge.as_undirected().fix())?;
 
             loop {
                 match trace_direction_out_of_edge(current_edge, line_from, line_to) {
@@ -462,7 +992,7 @@ where
                         panic!("Constraint edge does not end in a vertex. This is a bug.")
                     }
                     EdgeOutDirection::EdgeIntersection(edge) => {
-                        check_for_constraint_intersection(edge.as_undirected().fix());
+                        check_for_constraint_intersection(edge.as_undirected().fix())?;
 
                         let next = edge.rev().next();
 
@@ -535,7 +1065,7 @@ where
 
                         let constraint_edge = self.s().undirected_edge(constraint_edge).fix();
 
-                        result |= self.make_constraint_edge(constraint_edge);
+                        result |= self.make_constraint_edge(constraint_edge, id.clone());
 
                         // Don't mark the new constraint edge for legalization
                         isolation_result
@@ -589,9 +1119,11 @@ where
         }
     }
 
-    fn make_constraint_edge(&mut self, edge: FixedUndirectedEdgeHandle) -> bool {
+    fn make_constraint_edge(&mut self, edge: FixedUndirectedEdgeHandle, id: C) -> bool {
         if !self.is_constraint_edge(edge) {
-            self.s.undirected_edge_data_mut(edge).make_constraint_edge();
+            self.s
+                .undirected_edge_data_mut(edge)
+                .make_constraint_edge(id);
             self.num_constraints += 1;
             true
         } else {
@@ -599,8 +1131,162 @@ where
         }
     }
 
+    /// Removes a constraint edge.
+    ///
+    /// The edge itself remains part of the triangulation but is no longer marked as
+    /// constraint edge. Edges around it are flipped as required to restore the
+    /// constrained Delaunay property.
+    ///
+    /// Returns `true` if the given edge was a constraint edge. Returns `false` and does
+    /// nothing otherwise.
+    ///
+    /// # Example
+    /// ```
+    /// use spade::{ConstrainedDelaunayTriangulation, Point2, Triangulation};
+    /// # fn try_main() -> Result<(), spade::InsertionError> {
+    /// let mut cdt = ConstrainedDelaunayTriangulation::<Point2<_>>::new();
+    /// let v0 = cdt.insert(Point2::new(0f64, 0.0))?;
+    /// let v1 = cdt.insert(Point2::new(1.0, 0.0))?;
+    /// cdt.add_constraint(v0, v1);
+    ///
+    /// let edge = cdt.get_edge_from_neighbors(v0, v1).unwrap().as_undirected().fix();
+    /// assert!(cdt.remove_constraint(edge));
+    /// assert_eq!(cdt.num_constraints(), 0);
+    /// assert!(!cdt.exists_constraint(v0, v1));
+    /// # Ok(()) }
+    /// # fn main() { try_main().unwrap() }
+    /// ```
+    ///
+    /// # Handle invalidation
+    /// This method will not invalidate any vertex or face handles. Edge handles may refer
+    /// to different (flipped) edges after this call.
+    pub fn remove_constraint(&mut self, edge: FixedUndirectedEdgeHandle) -> bool {
+        if !self.is_constraint_edge(edge) {
+            return false;
+        }
+
+        self.s
+            .undirected_edge_data_mut(edge)
+            .unmake_constraint_edge();
+        self.num_constraints -= 1;
+
+        if !self.all_vertices_on_line() {
+            // Only the former constraint edge can violate the Delaunay property. Legalizing it
+            // may, in turn, require to legalize any edge of its flip polygon.
+            self.legalize_edges_after_removal(&mut vec![edge], |_| false);
+        }
+        true
+    }
+
+    /// Classifies all faces into faces inside and outside of the area enclosed by constraint
+    /// edges.
+    ///
+    /// Faces are classified with the even-odd rule: A face is inside if it is separated from the
+    /// outer face by an odd number of constraint edges. This works well for polygons with
+    /// holes as long as all polygon rings are closed. Use
+    /// [classify_inner_faces_with_rings](Self::classify_inner_faces_with_rings) if the polygon's
+    /// orientation matters.
+    ///
+    /// Returns one entry per face which can be looked up with `result[face.index()]`. The entry of
+    /// the outer face is always `false`.
+    ///
+    /// # Example
+    /// ```
+    /// use spade::{ConstrainedDelaunayTriangulation, Point2, Triangulation};
+    /// # fn try_main() -> Result<(), spade::InsertionError> {
+    /// let mut cdt = ConstrainedDelaunayTriangulation::<Point2<f64>>::new();
+    /// let triangle = [Point2::new(0.0, 0.0), Point2::new(2.0, 0.0), Point2::new(1.0, 2.0)];
+    /// cdt.add_constraint_edges(triangle, true)?;
+    /// cdt.insert(Point2::new(1.0, -2.0))?;
+    ///
+    /// let is_inside = cdt.classify_inner_faces();
+    /// let num_inside = cdt.inner_faces().filter(|face| is_inside[face.index()]).count();
+    /// assert_eq!(cdt.num_inner_faces(), 2);
+    /// assert_eq!(num_inside, 1);
+    /// # Ok(()) }
+    /// # fn main() { try_main().unwrap() }
+    /// ```
+    pub fn classify_inner_faces(&self) -> Vec<bool> {
+        let outer_faces = crate::delaunay_core::refinement::calculate_outer_faces(self);
+
+        let mut result = vec![false; self.num_all_faces()];
+        for face in self.fixed_inner_faces() {
+            result[face.index()] = !outer_faces.contains(&face);
+        }
+        result
+    }
+
+    /// Classifies all faces into faces inside and outside of a set of polygon rings.
+    ///
+    /// Each ring is given by a sequence of vertices. Rings are always closed, the last vertex is
+    /// connected to the first vertex. Consecutive ring vertices must be connected by constraint
+    /// edges. This includes constraints that were split into several constraint edges by
+    /// collinear vertices.
+    ///
+    /// In contrast to [classify_inner_faces](Self::classify_inner_faces), the orientation of the
+    /// rings is taken into account by calculating the winding number of each face. The given
+    /// [FillRule] determines which winding numbers are considered to be inside.
+    ///
+    /// Returns one entry per face which can be looked up with `result[face.index()]`. The entry of
+    /// the outer face is always `false`.
+    ///
+    /// # Panics
+    /// Panics if two consecutive vertices of a ring are not connected by constraint edges.
+    pub fn classify_inner_faces_with_rings(
+        &self,
+        rings: &[Vec<FixedVertexHandle>],
+        fill_rule: FillRule,
+    ) -> Vec<bool> {
+        // Counts how often each directed edge is part of a ring
+        let mut ring_edge_counts = vec![0i32; self.num_directed_edges()];
+        for ring in rings {
+            for (index, from) in ring.iter().enumerate() {
+                let to = ring[(index + 1) % ring.len()];
+                let chain = self
+                    .constraint_chain(*from, to)
+                    .expect("Ring vertices must be connected by constraint edges");
+                for edge in chain {
+                    ring_edge_counts[edge.index()] += 1;
+                }
+            }
+        }
+
+        // Flood fill starting at the outer face. Crossing a ring edge from its right to its left
+        // side increments the winding number.
+        let mut winding_numbers = vec![None; self.num_all_faces()];
+        let mut stack = Vec::new();
+        for edge in self.convex_hull() {
+            stack.push((edge.rev().fix(), 0));
+        }
+
+        while let Some((edge, outer_winding_number)) = stack.pop() {
+            let edge = self.directed_edge(edge);
+            let face = match edge.face().as_inner() {
+                Some(face) if winding_numbers[face.index()].is_none() => face,
+                _ => continue,
+            };
+
+            let winding_number = outer_winding_number + ring_edge_counts[edge.index()]
+                - ring_edge_counts[edge.rev().index()];
+            winding_numbers[face.index()] = Some(winding_number);
+            for next in [edge.next(), edge.prev()] {
+                stack.push((next.rev().fix(), winding_number));
+            }
+        }
+
+        winding_numbers
+            .into_iter()
+            .map(|winding_number| fill_rule.is_inside(winding_number.unwrap_or(0)))
+            .collect()
+    }
+
     #[cfg(test)]
     pub fn cdt_sanity_check(&self) {
+        self.cdt_sanity_check_with_params(true);
+    }
+
+    #[cfg(test)]
+    pub(crate) fn cdt_sanity_check_with_params(&self, check_delaunay_property: bool) {
         let num_undirected_edges = self
             .s
             .undirected_edges()
@@ -609,14 +1295,179 @@ where
 
         assert_eq!(num_undirected_edges, self.num_constraints());
         self.basic_sanity_check();
+
+        if !check_delaunay_property {
+            return;
+        }
+
+        // Any edge that is not a constraint edge must fulfill the Delaunay property
+        for edge in self.s.undirected_edges() {
+            if edge.is_constraint_edge() {
+                continue;
+            }
+
+            let edge = edge.as_directed();
+            if let (Some(left), Some(right)) =
+                (edge.opposite_position(), edge.rev().opposite_position())
+            {
+                assert!(!crate::delaunay_core::math::contained_in_circumference(
+                    edge.from().position(),
+                    edge.to().position(),
+                    left,
+                    right,
+                ));
+            }
+        }
+    }
+}
+
+impl<V, DE, UE, F, L, C> ConstrainedDelaunayTriangulation<V, DE, UE, F, L, C>
+where
+    V: HasPosition + From<Point2<<V as HasPosition>::Scalar>>,
+    DE: Default,
+    UE: Default,
+    F: Default,
+    L: HintGenerator<<V as HasPosition>::Scalar>,
+    C: Default + Clone,
+    <V as HasPosition>::Scalar: Float,
+{
+    /// Adds a constraint edge between two vertices and splits any intersecting constraint edge.
+    ///
+    /// In contrast to [add_constraint](Self::add_constraint), this method will not panic if the new
+    /// constraint intersects any existing constraint edge. Instead, a new vertex (a _steiner point_)
+    /// is inserted at every intersection. Both the new and the existing constraint are split at
+    /// these points. The vertex type must implement `From<Point2<...>>` to allow creating these
+    /// steiner points.
+    ///
+    /// Returns the directed constraint edges that connect `from` and `to`, ordered from `from` to
+    /// `to`. The result is empty if `from == to`.
+    ///
+    /// # Example
+    /// ```
+    /// use spade::{ConstrainedDelaunayTriangulation, Point2, Triangulation};
+    /// # fn try_main() -> Result<(), spade::InsertionError> {
+    /// let mut cdt = ConstrainedDelaunayTriangulation::<Point2<_>>::new();
+    /// cdt.add_constraint_edge(Point2::new(0.0, -1.0), Point2::new(0.0, 1.0))?;
+    ///
+    /// let v0 = cdt.insert(Point2::new(-1.0f64, 0.0))?;
+    /// let v1 = cdt.insert(Point2::new(1.0, 0.0))?;
+    /// // add_constraint(v0, v1) would panic
+    /// let new_edges = cdt.add_constraint_and_split(v0, v1);
+    ///
+    /// assert_eq!(new_edges.len(), 2);
+    /// assert_eq!(cdt.num_vertices(), 5);
+    /// assert_eq!(cdt.num_constraints(), 4);
+    /// assert!(cdt.locate_vertex(Point2::new(0.0, 0.0)).is_some());
+    /// # Ok(()) }
+    /// # fn main() { try_main().unwrap() }
+    /// ```
+    ///
+    /// # Precision
+    /// Intersection points are calculated with limited precision. The inserted steiner points will
+    /// not necessarily lie exactly on the line between `from` and `to`. If a steiner point cannot be
+    /// inserted without flipping an adjacent triangle, the nearest vertex of the split constraint
+    /// edge is used instead.
+    ///
+    /// # Handle invalidation
+    /// This method will invalidate all edge and face handles. Vertex handles remain valid.
+    pub fn add_constraint_and_split(
+        &mut self,
+        from: FixedVertexHandle,
+        to: FixedVertexHandle,
+    ) -> Vec<FixedDirectedEdgeHandle> {
+        let mut vertex_chain = vec![from];
+        let mut targets = vec![to];
+        let mut cur_from = from;
+
+        while let Some(&target) = targets.last() {
+            if cur_from == target {
+                targets.pop();
+                continue;
+            }
+
+            let conflict = LineIntersectionIterator::new_from_handles(self, cur_from, target)
+                .find_map(|intersection| match intersection {
+                    Intersection::EdgeIntersection(edge) if edge.is_constraint_edge() => {
+                        Some(edge.fix())
+                    }
+                    _ => None,
+                });
+
+            if let Some(conflict) = conflict {
+                // Split the conflicting edge and attempt to connect cur_from with the new vertex
+                let new_vertex = self.split_constraint_edge(conflict, cur_from, target);
+                targets.push(new_vertex);
+                continue;
+            }
+
+            self.add_constraint(cur_from, target);
+            vertex_chain.extend(
+                LineIntersectionIterator::new_from_handles(self, cur_from, target)
+                    .filter_map(|intersection| match intersection {
+                        Intersection::VertexIntersection(vertex) => Some(vertex.fix()),
+                        _ => None,
+                    })
+                    .skip(1),
+            );
+
+            cur_from = target;
+            targets.pop();
+        }
+
+        vertex_chain
+            .windows(2)
+            .map(|pair| {
+                self.get_edge_from_neighbors(pair[0], pair[1])
+                    .expect("Expected constraint edge. This is a bug.")
+                    .fix()
+            })
+            .collect()
+    }
+
+    /// Splits a constraint edge where it is intersected by the line from `line_from` to `line_to`.
+    ///
+    /// Returns the vertex that splits the edge.
+    fn split_constraint_edge(
+        &mut self,
+        edge: FixedDirectedEdgeHandle,
+        line_from: FixedVertexHandle,
+        line_to: FixedVertexHandle,
+    ) -> FixedVertexHandle {
+        let edge_handle = self.directed_edge(edge);
+        let [edge_from, edge_to] = edge_handle.positions();
+        let position = crate::delaunay_core::math::line_intersection(
+            self.vertex(line_from).position(),
+            self.vertex(line_to).position(),
+            edge_from,
+            edge_to,
+        );
+
+        if !crate::delaunay_core::refinement::validate_constructed_vertex(position, edge_handle) {
+            // Fall back to the closest vertex of the intersected edge
+            return if edge_from.distance_2(position) < edge_to.distance_2(position) {
+                edge_handle.from().fix()
+            } else {
+                edge_handle.to().fix()
+            };
+        }
+
+        let (new_vertex, split_parts) = self.insert_on_edge(edge, position.into());
+        self.handle_legal_edge_split(split_parts);
+        self.legalize_vertex(new_vertex);
+        self.hint_generator_mut()
+            .notify_vertex_inserted(new_vertex, position);
+        new_vertex
     }
 }
 
 #[cfg(test)]
 mod test {
-    use super::ConstrainedDelaunayTriangulation;
+    use super::{CdtBulkLoadError, ConstrainedDelaunayTriangulation, FillRule};
     use crate::test_utilities::*;
-    use crate::{DelaunayTriangulation, InsertionError, Point2, Triangulation};
+    use crate::{
+        DelaunayTriangulation, InsertionError, LastUsedVertexHintGenerator, Point2, Triangulation,
+        TriangulationExt,
+    };
     use rand::distributions::{Distribution, Uniform};
     use rand::{Rng, SeedableRng};
 
@@ -1063,6 +1914,507 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_remove_constraint() -> Result<(), InsertionError> {
+        let mut cdt = Cdt::new();
+        let v0 = cdt.insert(Point2::new(0.0, 0.0))?;
+        cdt.insert(Point2::new(1.0, 0.25))?;
+        cdt.insert(Point2::new(1.0, -0.25))?;
+        cdt.insert(Point2::new(2.0, 0.75))?;
+        cdt.insert(Point2::new(2.5, -0.3))?;
+        let v1 = cdt.insert(Point2::new(5.0, 0.0))?;
+        cdt.add_constraint(v0, v1);
+        assert_eq!(cdt.num_constraints(), 1);
+
+        let edge = cdt.get_edge_from_neighbors(v0, v1).unwrap().fix();
+        assert!(cdt.remove_constraint(edge.as_undirected()));
+        assert!(!cdt.remove_constraint(edge.as_undirected()));
+        assert_eq!(cdt.num_constraints(), 0);
+        assert!(!cdt.exists_constraint(v0, v1));
+        // The former constraint edge was not Delaunay and must have been flipped
+        assert!(cdt.get_edge_from_neighbors(v0, v1).is_none());
+        cdt.cdt_sanity_check();
+        Ok(())
+    }
+
+    #[test]
+    fn test_remove_constraint_degenerate() -> Result<(), InsertionError> {
+        let mut cdt = Cdt::new();
+        let v0 = cdt.insert(Point2::new(0.0, 0.0))?;
+        let v1 = cdt.insert(Point2::new(0.0, 1.0))?;
+        cdt.add_constraint(v0, v1);
+
+        let edge = cdt.get_edge_from_neighbors(v0, v1).unwrap().fix();
+        assert!(cdt.remove_constraint(edge.as_undirected()));
+        assert_eq!(cdt.num_constraints(), 0);
+        cdt.cdt_sanity_check();
+        Ok(())
+    }
+
+    #[test]
+    fn test_remove_all_constraints() -> Result<(), InsertionError> {
+        let mut cdt = Cdt::new();
+        for point in random_points_with_seed(200, SEED) {
+            cdt.insert(point)?;
+        }
+
+        const NUM_VERTICES: usize = 30;
+        let vertices = (0..NUM_VERTICES).map(|i| {
+            let angle = core::f64::consts::PI * 2.0 * i as f64 / NUM_VERTICES as f64;
+            let (sin, cos) = angle.sin_cos();
+            Point2::new(sin * 0.5, cos * 0.5)
+        });
+        cdt.add_constraint_edges(vertices, true)?;
+        cdt.cdt_sanity_check();
+
+        let constraints: Vec<_> = cdt
+            .undirected_edges()
+            .filter(|edge| edge.is_constraint_edge())
+            .map(|edge| edge.fix())
+            .collect();
+
+        for edge in constraints {
+            assert!(cdt.remove_constraint(edge));
+            cdt.cdt_sanity_check();
+        }
+
+        assert_eq!(cdt.num_constraints(), 0);
+        cdt.sanity_check();
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_constraint_and_split() -> Result<(), InsertionError> {
+        let mut cdt = Cdt::new();
+        cdt.add_constraint_edge(Point2::new(0.0, -1.0), Point2::new(0.0, 1.0))?;
+        cdt.add_constraint_edge(Point2::new(1.0, -1.0), Point2::new(1.0, 1.0))?;
+        let v0 = cdt.insert(Point2::new(-1.0, 0.0))?;
+        let v1 = cdt.insert(Point2::new(2.0, 0.0))?;
+
+        let edges = cdt.add_constraint_and_split(v0, v1);
+        assert_eq!(edges.len(), 3);
+        assert_eq!(cdt.num_vertices(), 8);
+        assert_eq!(cdt.num_constraints(), 7);
+
+        assert_eq!(cdt.directed_edge(edges[0]).from().fix(), v0);
+        assert_eq!(cdt.directed_edge(edges[2]).to().fix(), v1);
+        for pair in edges.windows(2) {
+            assert_eq!(
+                cdt.directed_edge(pair[0]).to(),
+                cdt.directed_edge(pair[1]).from()
+            );
+        }
+        for edge in edges {
+            assert!(cdt.directed_edge(edge).is_constraint_edge());
+        }
+
+        assert!(cdt.add_constraint_and_split(v0, v0).is_empty());
+        cdt.cdt_sanity_check();
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_constraint_and_split_random() -> Result<(), InsertionError> {
+        let mut cdt = Cdt::new();
+        for point in random_points_with_seed(100, SEED) {
+            cdt.insert(point)?;
+        }
+
+        let endpoints = random_points_in_range(0.9, 100, SEED2);
+        for pair in endpoints.chunks(2) {
+            let from = cdt.insert(pair[0])?;
+            let to = cdt.insert(pair[1])?;
+            let edges = cdt.add_constraint_and_split(from, to);
+            assert!(!edges.is_empty());
+            for edge in edges {
+                assert!(cdt.directed_edge(edge).is_constraint_edge());
+            }
+            cdt.cdt_sanity_check();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_bulk_load_cdt() -> Result<(), CdtBulkLoadError> {
+        const NUM_VERTICES: usize = 51;
+        let mut vertices: Vec<_> = (0..NUM_VERTICES)
+            .map(|i| {
+                let angle = core::f64::consts::PI * 2.0 * i as f64 / NUM_VERTICES as f64;
+                let (sin, cos) = angle.sin_cos();
+                Point2::new(sin * 2.0, cos * 2.0)
+            })
+            .collect();
+        let mut edges: Vec<_> = (0..NUM_VERTICES)
+            .map(|i| [i, (i + 1) % NUM_VERTICES])
+            .collect();
+
+        // Add some inner vertices and a duplicate
+        vertices.extend(random_points_with_seed(100, SEED));
+        vertices.push(vertices[0]);
+        edges.push([vertices.len() - 1, NUM_VERTICES + 3]);
+
+        let cdt = Cdt::bulk_load_cdt(vertices, edges)?;
+        assert_eq!(cdt.num_vertices(), NUM_VERTICES + 100);
+        assert_eq!(cdt.num_constraints(), NUM_VERTICES + 1);
+        cdt.cdt_sanity_check();
+        Ok(())
+    }
+
+    #[test]
+    fn test_move_vertex() -> Result<(), InsertionError> {
+        let mut cdt = Cdt::new();
+        let v0 = cdt.insert(Point2::new(0.0, 0.0))?;
+        let v1 = cdt.insert(Point2::new(1.0, 0.0))?;
+        let v2 = cdt.insert(Point2::new(1.0, 1.0))?;
+        for point in random_points_with_seed(50, SEED) {
+            cdt.insert(point.mul(0.3).add(Point2::new(-1.0, 0.5)))?;
+        }
+        cdt.add_constraint(v0, v1);
+        cdt.add_constraint(v1, v2);
+        let unconstrained = crate::handles::FixedVertexHandle::new(10);
+
+        // Small moves within the star
+        cdt.move_vertex(v1, Point2::new(1.01, 0.02))?;
+        cdt.move_vertex(
+            unconstrained,
+            cdt.vertex(unconstrained)
+                .position()
+                .add(Point2::new(0.001, 0.0)),
+        )?;
+        assert!(cdt.exists_constraint(v0, v1));
+        assert!(cdt.exists_constraint(v1, v2));
+        assert_eq!(cdt.num_constraints(), 2);
+        cdt.cdt_sanity_check();
+
+        // Large moves
+        assert!(cdt.move_vertex(v1, Point2::new(3.0, -2.0))?);
+        assert!(cdt.exists_constraint(v0, v1));
+        assert!(cdt.exists_constraint(v1, v2));
+        assert_eq!(cdt.num_constraints(), 2);
+        cdt.cdt_sanity_check();
+
+        // Moving a vertex onto a constraint edge splits it
+        assert!(cdt.move_vertex(unconstrained, Point2::new(1.5, -1.0))?);
+        assert_eq!(cdt.num_constraints(), 3);
+        cdt.cdt_sanity_check();
+
+        assert!(!cdt.move_vertex(v0, Point2::new(1.0, 1.0))?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_remove_convex_hull_vertex_with_constraints() -> Result<(), InsertionError> {
+        let mut cdt = Cdt::new();
+        for point in random_points_with_seed(50, SEED) {
+            cdt.insert(point)?;
+        }
+        let hull_vertex = cdt.insert(Point2::new(-3.0, 0.0))?;
+        let inner_vertex = cdt.insert(Point2::new(0.0, 0.01))?;
+        cdt.add_constraint(hull_vertex, inner_vertex);
+
+        cdt.remove(hull_vertex);
+        assert_eq!(cdt.num_constraints(), 0);
+        cdt.cdt_sanity_check();
+        Ok(())
+    }
+
+    #[test]
+    fn test_move_convex_hull_vertex_with_constraints() -> Result<(), InsertionError> {
+        let mut cdt = Cdt::new();
+        for point in random_points_with_seed(50, SEED) {
+            cdt.insert(point)?;
+        }
+        let hull_vertex = cdt.insert(Point2::new(-3.0, 0.0))?;
+        let inner_vertex = cdt.insert(Point2::new(0.0, 0.01))?;
+        cdt.add_constraint(hull_vertex, inner_vertex);
+
+        assert!(cdt.move_vertex(hull_vertex, Point2::new(0.01, 3.0))?);
+        assert!(cdt.exists_constraint(hull_vertex, inner_vertex));
+        assert_eq!(cdt.num_constraints(), 1);
+        cdt.cdt_sanity_check();
+        Ok(())
+    }
+
+    #[test]
+    fn test_constraint_ids() -> Result<(), InsertionError> {
+        // Constraints added without an id store `None`
+        let mut cdt = ConstrainedDelaunayTriangulation::<
+            Point2<f64>,
+            (),
+            (),
+            (),
+            LastUsedVertexHintGenerator,
+            Option<usize>,
+        >::new();
+        for point in random_points_with_seed(50, SEED) {
+            cdt.insert(point)?;
+        }
+        let river = [
+            Point2::new(-0.9, -0.9),
+            Point2::new(-0.3, 0.1),
+            Point2::new(0.9, 0.2),
+        ];
+        cdt.add_constraint_edges_with_id(river, false, Some(1))?;
+        let v0 = cdt.insert(Point2::new(-0.9, 0.9))?;
+        let v1 = cdt.insert(Point2::new(0.9, 0.9))?;
+        assert!(cdt.add_constraint_with_id(v0, v1, Some(2)));
+        let v2 = cdt.insert(Point2::new(0.95, -0.95))?;
+        assert!(cdt.add_constraint(v1, v2));
+
+        assert_eq!(
+            cdt.get_edge_from_neighbors(v1, v2).unwrap().constraint_id(),
+            Some(&None)
+        );
+        for edge in cdt.undirected_edges() {
+            if !edge.is_constraint_edge() {
+                assert_eq!(edge.constraint_id(), None);
+            }
+        }
+
+        // Splitting a constraint edge keeps its id
+        let split_vertex = cdt.insert(Point2::new(0.0, 0.9))?;
+        for edge in cdt.vertex(split_vertex).out_edges() {
+            if edge.is_constraint_edge() {
+                assert_eq!(edge.constraint_id(), Some(&Some(2)));
+            }
+        }
+
+        // Moving a vertex keeps the ids of its constraint edges
+        cdt.move_vertex(v0, Point2::new(-0.5, 1.5))?;
+        for edge in cdt.vertex(v0).out_edges() {
+            if edge.is_constraint_edge() {
+                assert_eq!(edge.constraint_id(), Some(&Some(2)));
+            }
+        }
+
+        // Refinement keeps ids as well
+        let num_constraints = cdt.num_constraints();
+        cdt.refine(crate::RefinementParameters::new().with_max_allowed_area(0.01));
+        assert!(cdt.num_constraints() > num_constraints);
+        let mut num_river_edges = 0;
+        for edge in cdt.undirected_edges() {
+            if !edge.is_constraint_edge() {
+                continue;
+            }
+            let [from, to] = edge.positions();
+            match edge.constraint_id() {
+                Some(Some(1)) => num_river_edges += 1,
+                Some(Some(2)) => assert!(from.y > 0.89 && to.y > 0.89),
+                Some(None) => assert!(from.x > 0.89 && to.x > 0.89),
+                _ => panic!("Unexpected constraint id"),
+            }
+        }
+        assert!(num_river_edges > 2);
+
+        // Removed constraints don't have an id
+        let edge = cdt
+            .vertex(v0)
+            .out_edges()
+            .find(|edge| edge.is_constraint_edge())
+            .unwrap()
+            .fix()
+            .as_undirected();
+        assert!(cdt.remove_constraint(edge));
+        assert_eq!(cdt.undirected_edge(edge).constraint_id(), None);
+        cdt.cdt_sanity_check();
+        Ok(())
+    }
+
+    #[test]
+    fn test_constraint_chain() -> Result<(), InsertionError> {
+        let mut cdt = Cdt::new();
+        let v0 = cdt.insert(Point2::new(0.0, 0.0))?;
+        let v1 = cdt.insert(Point2::new(4.0, 0.0))?;
+        let v2 = cdt.insert(Point2::new(2.0, 2.0))?;
+        cdt.insert(Point2::new(2.0, -2.0))?;
+        cdt.add_constraint(v0, v1);
+        assert_eq!(
+            cdt.constraint_chain(v0, v1).map(|chain| chain.len()),
+            Some(1)
+        );
+
+        let v3 = cdt.insert(Point2::new(1.0, 0.0))?;
+        cdt.insert(Point2::new(3.0, 0.0))?;
+        assert!(!cdt.exists_constraint(v0, v1));
+
+        let chain = cdt.constraint_chain(v0, v1).unwrap();
+        let vertices = chain
+            .iter()
+            .map(|edge| cdt.directed_edge(*edge).from().fix())
+            .collect::<Vec<_>>();
+        assert_eq!(chain.len(), 3);
+        assert_eq!(vertices[0], v0);
+        assert_eq!(vertices[1], v3);
+        assert_eq!(cdt.directed_edge(chain[2]).to().fix(), v1);
+
+        let reversed = cdt.constraint_chain(v1, v0).unwrap();
+        assert_eq!(reversed.len(), 3);
+        assert_eq!(reversed[0], chain[2].rev());
+
+        assert_eq!(
+            cdt.constraint_chain(v3, v1).map(|chain| chain.len()),
+            Some(2)
+        );
+        assert_eq!(cdt.constraint_chain(v0, v0), Some(Vec::new()));
+        assert_eq!(cdt.constraint_chain(v0, v2), None);
+
+        cdt.remove_constraint(chain[1].as_undirected());
+        assert_eq!(cdt.constraint_chain(v0, v1), None);
+        assert_eq!(
+            cdt.constraint_chain(v0, v3).map(|chain| chain.len()),
+            Some(1)
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_deserialize_cdt_edge_without_constraint_id() {
+        use super::CdtEdge;
+
+        // Format used before constraint ids were added
+        let edge: CdtEdge<(), Option<usize>> = serde_json::from_str("[true,null]").unwrap();
+        assert!(edge.is_constraint_edge());
+        assert_eq!(edge.constraint_id(), Some(&None));
+
+        let mut edge = CdtEdge::<(), Option<usize>>::default();
+        edge.make_constraint_edge(Some(3));
+        let serialized = serde_json::to_string(&edge).unwrap();
+        let deserialized: CdtEdge<(), Option<usize>> = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, edge);
+        assert_eq!(deserialized.constraint_id(), Some(&Some(3)));
+    }
+
+    #[test]
+    fn test_cdt_edge_size() {
+        use super::CdtEdge;
+
+        // The default constraint id type doesn't take up any space
+        assert_eq!(core::mem::size_of::<CdtEdge<()>>(), 1);
+        assert_eq!(
+            core::mem::size_of::<CdtEdge<u64>>(),
+            core::mem::size_of::<(bool, u64)>()
+        );
+    }
+
+    #[test]
+    fn test_lloyd_relaxation() -> Result<(), CdtBulkLoadError> {
+        use crate::FloatTriangulation;
+
+        const NUM_VERTICES: usize = 20;
+        let mut vertices: Vec<_> = (0..NUM_VERTICES)
+            .map(|i| {
+                let angle = core::f64::consts::PI * 2.0 * i as f64 / NUM_VERTICES as f64;
+                let (sin, cos) = angle.sin_cos();
+                Point2::new(sin * 2.0, cos * 2.0)
+            })
+            .collect();
+        let edges: Vec<_> = (0..NUM_VERTICES)
+            .map(|i| [i, (i + 1) % NUM_VERTICES])
+            .collect();
+        let constraint_vertices = vertices.clone();
+        vertices.extend(random_points_with_seed(50, SEED));
+
+        let mut cdt = Cdt::bulk_load_cdt(vertices, edges)?;
+        let bounds = [
+            Point2::new(-2.0, -2.0),
+            Point2::new(2.0, -2.0),
+            Point2::new(2.0, 2.0),
+            Point2::new(-2.0, 2.0),
+        ];
+        cdt.lloyd_relaxation(10, &bounds);
+
+        assert_eq!(cdt.num_vertices(), NUM_VERTICES + 50);
+        assert_eq!(cdt.num_constraints(), NUM_VERTICES);
+        for position in constraint_vertices {
+            assert!(cdt.locate_vertex(position).is_some());
+        }
+        cdt.cdt_sanity_check();
+        Ok(())
+    }
+
+    #[test]
+    fn test_bulk_load_cdt_random() -> Result<(), CdtBulkLoadError> {
+        let vertices = random_points_with_seed(1000, SEED2);
+        // Use the edges of a Delaunay triangulation to generate non intersecting constraint edges
+        let mut delaunay = Delaunay::new();
+        for vertex in &vertices {
+            delaunay.insert(*vertex)?;
+        }
+
+        // Vertex handles match the input indices as all vertices are unique
+        let edges: Vec<_> = delaunay
+            .undirected_edges()
+            .step_by(3)
+            .map(|edge| edge.vertices().map(|v| v.fix().index()))
+            .collect();
+        let num_edges = edges.len();
+
+        let cdt = Cdt::bulk_load_cdt(vertices, edges)?;
+        assert_eq!(cdt.num_vertices(), 1000);
+        assert_eq!(cdt.num_constraints(), num_edges);
+        cdt.cdt_sanity_check();
+        Ok(())
+    }
+
+    #[test]
+    fn test_bulk_load_cdt_long_edges() -> Result<(), CdtBulkLoadError> {
+        // Long edges span most of the triangulation and cannot be added during the sweep
+        let mut vertices = random_points_with_seed(500, SEED);
+        let mut edges = Vec::new();
+        for i in 0..10 {
+            let y = -0.95 + 0.2 * i as f64;
+            edges.push([vertices.len(), vertices.len() + 1]);
+            vertices.push(Point2::new(-1.5, y));
+            vertices.push(Point2::new(1.5, y + 0.05));
+        }
+
+        let cdt = Cdt::bulk_load_cdt(vertices.clone(), edges.clone())?;
+        cdt.cdt_sanity_check();
+
+        let mut expected = Cdt::new();
+        let handles = vertices
+            .into_iter()
+            .map(|vertex| expected.insert(vertex))
+            .collect::<Result<Vec<_>, _>>()?;
+        for [from, to] in edges {
+            expected.add_constraint(handles[from], handles[to]);
+        }
+        assert_eq!(cdt.num_vertices(), expected.num_vertices());
+        assert_eq!(cdt.num_constraints(), expected.num_constraints());
+        Ok(())
+    }
+
+    #[test]
+    fn test_bulk_load_cdt_intersecting_edges() -> Result<(), InsertionError> {
+        let vertices = vec![
+            Point2::new(0.0, 0.0),
+            Point2::new(1.0, 1.0),
+            Point2::new(1.0, 0.0),
+            Point2::new(0.0, 1.0),
+            Point2::new(2.0, 2.0),
+        ];
+
+        let edges = vec![[0, 1], [1, 4], [2, 3]];
+        assert!(matches!(
+            Cdt::bulk_load_cdt(vertices.clone(), edges).err(),
+            Some(CdtBulkLoadError::IntersectingConstraintEdges(0 | 2))
+        ));
+
+        // Overlapping edges are allowed
+        let edges = vec![[0, 1], [0, 4]];
+        let cdt = Cdt::bulk_load_cdt(vertices, edges).unwrap();
+        assert_eq!(cdt.num_constraints(), 2);
+        cdt.cdt_sanity_check();
+
+        let invalid = vec![Point2::new(f64::NAN, 0.0)];
+        assert_eq!(
+            Cdt::bulk_load_cdt(invalid, Vec::new()).err(),
+            Some(CdtBulkLoadError::InsertionError(InsertionError::NAN))
+        );
+        Ok(())
+    }
+
     #[test]
     fn test_crash_scenario() -> Result<(), InsertionError> {
         let mut cdt = Cdt::new();
@@ -1182,6 +2534,98 @@ mod test {
         Ok(())
     }
 
+    fn square(min: f64, max: f64) -> Vec<Point2<f64>> {
+        vec![
+            Point2::new(min, min),
+            Point2::new(max, min),
+            Point2::new(max, max),
+            Point2::new(min, max),
+        ]
+    }
+
+    fn inside_area(cdt: &Cdt, is_inside: &[bool]) -> f64 {
+        cdt.inner_faces()
+            .filter(|face| is_inside[face.index()])
+            .map(|face| face.area())
+            .sum()
+    }
+
+    #[test]
+    fn test_classify_inner_faces() -> Result<(), InsertionError> {
+        let mut cdt = Cdt::new();
+        cdt.add_constraint_edges(square(0.0, 6.0), true)?;
+        cdt.add_constraint_edges(square(1.0, 5.0), true)?;
+        // An island within the hole
+        cdt.add_constraint_edges(square(2.0, 3.0), true)?;
+        for point in random_points_in_range(10.0, 100, SEED) {
+            if !cdt.intersects_constraint(point, point) {
+                cdt.insert(point)?;
+            }
+        }
+
+        let is_inside = cdt.classify_inner_faces();
+        assert_eq!(is_inside.len(), cdt.num_all_faces());
+        assert!(!is_inside[0]);
+        assert!((inside_area(&cdt, &is_inside) - 21.0).abs() < 1e-9);
+        Ok(())
+    }
+
+    #[test]
+    fn test_classify_inner_faces_with_rings() -> Result<(), InsertionError> {
+        for reverse_hole in [false, true] {
+            let mut hole = square(1.0, 2.0);
+            if reverse_hole {
+                hole.reverse();
+            }
+
+            let mut cdt = Cdt::new();
+            let mut rings = Vec::new();
+            for ring in [square(0.0, 3.0), hole] {
+                let handles = ring
+                    .into_iter()
+                    .map(|point| cdt.insert(point))
+                    .collect::<Result<Vec<_>, _>>()?;
+                for (index, from) in handles.iter().enumerate() {
+                    cdt.add_constraint(*from, handles[(index + 1) % handles.len()]);
+                }
+                rings.push(handles);
+            }
+            // Splits a ring edge into two constraint edges
+            cdt.insert(Point2::new(1.5, 0.0))?;
+
+            let even_odd = cdt.classify_inner_faces_with_rings(&rings, FillRule::EvenOdd);
+            assert_eq!(even_odd, cdt.classify_inner_faces());
+            assert!((inside_area(&cdt, &even_odd) - 8.0).abs() < 1e-9);
+
+            let non_zero = cdt.classify_inner_faces_with_rings(&rings, FillRule::NonZero);
+            let expected_area = if reverse_hole { 8.0 } else { 9.0 };
+            assert!((inside_area(&cdt, &non_zero) - expected_area).abs() < 1e-9);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_bulk_load_polygons() -> Result<(), CdtBulkLoadError> {
+        let rings = vec![square(0.0, 4.0), square(1.0, 2.0), square(2.5, 3.5)];
+        let (cdt, interior_faces) = Cdt::bulk_load_polygons(rings, FillRule::EvenOdd)?;
+
+        cdt.cdt_sanity_check();
+        assert_eq!(cdt.num_constraints(), 12);
+        let area: f64 = interior_faces
+            .iter()
+            .map(|face| cdt.face(*face).area())
+            .sum();
+        assert!((area - 14.0).abs() < 1e-9);
+
+        // Edge 1 intersects edge 4, edge 2 intersects edge 7
+        let rings = vec![square(0.0, 2.0), square(1.0, 3.0)];
+        assert!(matches!(
+            Cdt::bulk_load_polygons(rings, FillRule::EvenOdd).map(|_| ()),
+            Err(CdtBulkLoadError::IntersectingConstraintEdges(1 | 2 | 4 | 7))
+        ));
+        Ok(())
+    }
+
     #[test]
     fn infinite_loop_bug() -> Result<(), InsertionError> {
         // See https://github.com/Stoeoef/spade/issues/98
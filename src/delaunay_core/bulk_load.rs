@@ -1,8 +1,12 @@
 use core::cmp::{Ordering, Reverse};
 
-use crate::{HasPosition, InsertionError, Point2, Triangulation, TriangulationExt};
+use crate::{
+    HasPosition, InsertionError, Point2, StableBulkLoadError, Triangulation, TriangulationExt,
+};
 
-use super::{dcel_operations, FixedDirectedEdgeHandle, FixedUndirectedEdgeHandle};
+use super::{
+    dcel_operations, FixedDirectedEdgeHandle, FixedUndirectedEdgeHandle, FixedVertexHandle,
+};
 
 use alloc::vec::Vec;
 
@@ -68,13 +72,46 @@ impl Eq for FloatOrd {}
 /// In rare cases, step 6 is not able to insert a vertex properly. It will be skipped and inserted
 /// regularly at the end (slow path). This may happen especially for very skewed triangulations
 /// and might be a good point for investigation if some point sets takes surprisingly long to load.
-pub fn bulk_load<V, T>(mut elements: Vec<V>) -> Result<T, InsertionError>
+pub fn bulk_load<V, T>(elements: Vec<V>) -> Result<T, InsertionError>
 where
     V: HasPosition,
     T: Triangulation<Vertex = V>,
+{
+    bulk_load_with_vertex_handles(elements).map(|(result, _)| result)
+}
+
+/// Same as [bulk_load] but also returns the vertex handle of every input element.
+///
+/// The returned `Vec` has the same length as the input. Its i-th entry refers to the vertex that
+/// was created for the i-th input element. Duplicated input positions will refer to the same vertex.
+pub fn bulk_load_with_vertex_handles<V, T>(
+    elements: Vec<V>,
+) -> Result<(T, Vec<FixedVertexHandle>), InsertionError>
+where
+    V: HasPosition,
+    T: Triangulation<Vertex = V>,
+{
+    bulk_load_with_callback(elements, |_, _, _| Ok(()))
+}
+
+/// Same as [bulk_load_with_vertex_handles] but calls `on_insert` after each inserted element.
+///
+/// `on_insert` receives the triangulation, the input index of the inserted element and its vertex
+/// handle. The triangulation's hull is not necessarily convex when `on_insert` is called. Hence,
+/// `on_insert` must not change any edge of the hull. Any error returned by `on_insert` stops
+/// the bulk load.
+pub fn bulk_load_with_callback<V, T, E, F>(
+    elements: Vec<V>,
+    mut on_insert: F,
+) -> Result<(T, Vec<FixedVertexHandle>), E>
+where
+    V: HasPosition,
+    T: Triangulation<Vertex = V>,
+    E: From<InsertionError>,
+    F: FnMut(&mut T, usize, FixedVertexHandle) -> Result<(), E>,
 {
     if elements.is_empty() {
-        return Ok(T::new());
+        return Ok((T::new(), Vec::new()));
     }
 
     let mut point_sum = Point2::<f64>::new(0.0, 0.0);
@@ -98,13 +135,19 @@ where
 
     let mut result = T::with_capacity(elements.len(), elements.len() * 3, elements.len() * 2);
 
+    // Maps each input index to the handle of its vertex. Every entry is overwritten before returning.
+    let mut handles = alloc::vec![FixedVertexHandle::new(0); elements.len()];
+
+    let mut elements: Vec<_> = elements.into_iter().enumerate().collect();
+
     // Sort by distance, smallest values last. This allows to pop values depending on their distance.
-    elements.sort_unstable_by_key(|e| {
+    elements.sort_unstable_by_key(|(_, e)| {
         Reverse(FloatOrd(initial_center.distance_2(e.position().to_f64())))
     });
 
-    while let Some(next) = elements.pop() {
-        result.insert(next)?;
+    while let Some((index, next)) = elements.pop() {
+        handles[index] = result.insert(next)?;
+        on_insert(&mut result, index, handles[index])?;
         if !result.all_vertices_on_line() && result.num_vertices() >= 4 {
             // We'll need 4 vertices to calculate a center position with good precision.
             // Otherwise, dividing by 3.0 can introduce precision loss and errors.
@@ -113,7 +156,7 @@ where
     }
 
     if elements.is_empty() {
-        return Ok(result);
+        return Ok((result, handles));
     }
 
     // Get new center that is guaranteed to be within the convex hull
@@ -142,19 +185,24 @@ where
 
         // The hull cannot be constructed in some rare cases for very degenerate
         // triangulations. Just insert another vertex and try again. Usually hull generation should succeed eventually.
-        if let Some(next) = elements.pop() {
-            result.insert(next).unwrap();
+        if let Some((index, next)) = elements.pop() {
+            handles[index] = result.insert(next).unwrap();
+            on_insert(&mut result, index, handles[index])?;
         } else {
-            return Ok(result);
+            return Ok((result, handles));
         }
     };
 
     let mut buffer = Vec::new();
     let mut skipped_elements = Vec::new();
-    while let Some(next) = elements.pop() {
-        skipped_elements.extend(
-            single_bulk_insertion_step(&mut result, center, &mut hull, next, &mut buffer).err(),
-        );
+    while let Some((index, next)) = elements.pop() {
+        match single_bulk_insertion_step(&mut result, center, &mut hull, next, &mut buffer) {
+            Ok(new_vertex) => {
+                handles[index] = new_vertex;
+                on_insert(&mut result, index, new_vertex)?;
+            }
+            Err(skipped) => skipped_elements.push((index, skipped)),
+        }
     }
 
     if cfg!(any(fuzzing, test)) {
@@ -163,13 +211,151 @@ where
 
     fix_convexity(&mut result);
 
-    for element in skipped_elements {
-        result.insert(element)?;
+    for (index, element) in skipped_elements {
+        handles[index] = result.insert(element)?;
+        on_insert(&mut result, index, handles[index])?;
     }
 
+    Ok((result, handles))
+}
+
+/// Bulk loads a triangulation while keeping the order of the input elements.
+///
+/// The triangulation's vertices are reordered after loading to match the order of the input
+/// elements. Fails if any element has the same position as a previous element.
+pub fn bulk_load_stable<V, T>(elements: Vec<V>) -> Result<T, StableBulkLoadError>
+where
+    V: HasPosition,
+    T: Triangulation<Vertex = V>,
+{
+    for element in &elements {
+        crate::validate_vertex(element)?;
+    }
+
+    // Identify duplicates by sorting all positions lexicographically. The stable sort ensures
+    // that the first occurrence of each position comes first.
+    let mut sorted_indices: Vec<_> = (0..elements.len()).collect();
+    sorted_indices.sort_by(|l, r| {
+        elements[*l]
+            .position()
+            .partial_cmp(&elements[*r].position())
+            .unwrap()
+    });
+
+    let mut first_duplicate: Option<(usize, usize)> = None;
+    let mut first_occurrence = None;
+    for pair in sorted_indices.windows(2) {
+        if elements[pair[0]].position() == elements[pair[1]].position() {
+            let duplicate = (pair[1], *first_occurrence.get_or_insert(pair[0]));
+            first_duplicate = Some(first_duplicate.map_or(duplicate, |d| duplicate.min(d)));
+        } else {
+            first_occurrence = None;
+        }
+    }
+
+    if let Some((index, merged_into)) = first_duplicate {
+        return Err(StableBulkLoadError::MergedElement { index, merged_into });
+    }
+
+    let (mut result, handles): (T, _) = bulk_load_with_vertex_handles(elements)?;
+
+    // handles contains no duplicates and is a permutation of all vertex handles.
+    let mut new_indices = alloc::vec![FixedVertexHandle::new(0); handles.len()];
+    for (index, handle) in handles.into_iter().enumerate() {
+        new_indices[handle.index()] = FixedVertexHandle::new(index);
+    }
+
+    dcel_operations::reorder_vertices(result.s_mut(), &new_indices);
+
     Ok(result)
 }
 
+/// Inserts a batch of elements into an existing triangulation.
+///
+/// The elements are sorted along a Hilbert curve and inserted one by one. Consecutive elements
+/// are close to each other which allows to use the previously inserted vertex as hint.
+///
+/// Returns the vertex handle of every input element in input order.
+pub fn insert_bulk<V, T>(
+    triangulation: &mut T,
+    elements: Vec<V>,
+) -> Result<Vec<FixedVertexHandle>, InsertionError>
+where
+    V: HasPosition,
+    T: Triangulation<Vertex = V>,
+{
+    for element in &elements {
+        crate::validate_vertex(element)?;
+    }
+
+    let mut min = Point2::new(f64::MAX, f64::MAX);
+    let mut max = Point2::new(f64::MIN, f64::MIN);
+    for element in &elements {
+        let position = element.position().to_f64();
+        min = Point2::new(min.x.min(position.x), min.y.min(position.y));
+        max = Point2::new(max.x.max(position.x), max.y.max(position.y));
+    }
+
+    let extent = (max.x - min.x).max(max.y - min.y);
+    let scale = if extent > 0.0 {
+        (HILBERT_GRID_SIZE - 1) as f64 / extent
+    } else {
+        0.0
+    };
+
+    let mut sorted: Vec<_> = elements
+        .into_iter()
+        .enumerate()
+        .map(|(index, element)| {
+            let position = element.position().to_f64().sub(min).mul(scale);
+            let key = hilbert_index(position.x as u32, position.y as u32);
+            (key, index, element)
+        })
+        .collect();
+    sorted.sort_unstable_by_key(|(key, index, _)| (*key, *index));
+
+    let mut handles = alloc::vec![FixedVertexHandle::new(0); sorted.len()];
+    let mut hint = None;
+    for (_, index, element) in sorted {
+        let handle = match hint {
+            Some(hint) => triangulation.insert_with_hint(element, hint)?,
+            None => triangulation.insert(element)?,
+        };
+        handles[index] = handle;
+        hint = Some(handle);
+    }
+
+    Ok(handles)
+}
+
+const HILBERT_ORDER: u32 = 16;
+const HILBERT_GRID_SIZE: u32 = 1 << HILBERT_ORDER;
+
+/// Returns the distance of a grid cell along a Hilbert curve covering a grid of
+/// `HILBERT_GRID_SIZE * HILBERT_GRID_SIZE` cells.
+///
+/// See <https://en.wikipedia.org/wiki/Hilbert_curve>
+fn hilbert_index(mut x: u32, mut y: u32) -> u64 {
+    let mut result = 0;
+    let mut cell_size = HILBERT_GRID_SIZE / 2;
+    while cell_size > 0 {
+        let rx = (x & cell_size > 0) as u32;
+        let ry = (y & cell_size > 0) as u32;
+        result += cell_size as u64 * cell_size as u64 * ((3 * rx) ^ ry) as u64;
+
+        // Rotate the quadrant to continue the curve in the right orientation
+        if ry == 0 {
+            if rx == 1 {
+                x = HILBERT_GRID_SIZE - 1 - x;
+                y = HILBERT_GRID_SIZE - 1 - y;
+            }
+            core::mem::swap(&mut x, &mut y);
+        }
+        cell_size /= 2;
+    }
+    result
+}
+
 #[inline(never)] // Prevent inlining for better profiling data
 fn single_bulk_insertion_step<TR, T>(
     result: &mut TR,
@@ -177,7 +363,7 @@ fn single_bulk_insertion_step<TR, T>(
     hull: &mut Hull,
     element: T,
     buffer_for_edge_legalization: &mut Vec<FixedUndirectedEdgeHandle>,
-) -> Result<(), T>
+) -> Result<FixedVertexHandle, T>
 where
     T: HasPosition,
     TR: Triangulation<Vertex = T>,
@@ -287,6 +473,7 @@ where
         }
     }
 
+    let new_vertex_handle = new_vertex;
     let new_vertex = result.vertex(new_vertex);
     let outgoing_ch_edge = new_vertex.out_edges().find(|edge| edge.is_outer_edge());
 
@@ -305,7 +492,7 @@ where
             second_edge.fix(),
         );
     }
-    Ok(())
+    Ok(new_vertex_handle)
 }
 
 /// Makes the outer hull convex. Similar to a graham scan.
@@ -749,13 +936,16 @@ mod test {
     use float_next_after::NextAfter;
     use rand::{seq::SliceRandom, SeedableRng};
 
-    use crate::test_utilities::{random_points_with_seed, SEED2};
+    use crate::test_utilities::{random_points_with_seed, SEED, SEED2};
 
-    use crate::{DelaunayTriangulation, InsertionError, Point2, Triangulation, TriangulationExt};
+    use crate::{
+        DelaunayTriangulation, InsertionError, Point2, StableBulkLoadError, Triangulation,
+        TriangulationExt,
+    };
 
     use super::Hull;
 
-    use alloc::vec::Vec;
+    use alloc::{vec, vec::Vec};
 
     #[test]
     fn test_bulk_load_with_small_number_of_vertices() -> Result<(), InsertionError> {
@@ -853,6 +1043,143 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_bulk_load_with_vertex_handles() -> Result<(), InsertionError> {
+        let mut vertices = random_points_with_seed(1000, SEED);
+        // Add some duplicates
+        vertices.extend_from_within(100..200);
+
+        let (triangulation, handles): (DelaunayTriangulation<_>, _) =
+            super::bulk_load_with_vertex_handles(vertices.clone())?;
+
+        triangulation.sanity_check();
+        assert_eq!(triangulation.num_vertices(), 1000);
+        assert_eq!(handles.len(), vertices.len());
+        for (handle, vertex) in handles.iter().zip(vertices) {
+            assert_eq!(triangulation.vertex(*handle).position(), vertex);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_bulk_load_stable() -> Result<(), StableBulkLoadError> {
+        let vertices = random_points_with_seed(1000, SEED);
+        let triangulation: DelaunayTriangulation<_> = super::bulk_load_stable(vertices.clone())?;
+
+        triangulation.sanity_check();
+        assert_eq!(triangulation.num_vertices(), 1000);
+        for (index, (vertex, expected)) in triangulation.vertices().zip(vertices).enumerate() {
+            assert_eq!(vertex.fix().index(), index);
+            assert_eq!(vertex.position(), expected);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_bulk_load_stable_with_duplicates() {
+        let mut vertices = random_points_with_seed(1000, SEED);
+        // Add some duplicates
+        vertices.extend_from_within(300..400);
+        vertices.insert(10, vertices[500]);
+
+        let result: Result<DelaunayTriangulation<_>, _> = super::bulk_load_stable(vertices);
+        assert_eq!(
+            result.err(),
+            Some(StableBulkLoadError::MergedElement {
+                index: 501,
+                merged_into: 10
+            })
+        );
+
+        let vertices = vec![
+            Point2::new(0.0, 1.0),
+            Point2::new(4.0, 2.0),
+            Point2::new(1.0, 0.0),
+            Point2::new(4.0, 2.0),
+            Point2::new(0.0, 1.0),
+            Point2::new(3.0, 3.0),
+        ];
+
+        let result: Result<DelaunayTriangulation<_>, _> = super::bulk_load_stable(vertices);
+        assert_eq!(
+            result.err(),
+            Some(StableBulkLoadError::MergedElement {
+                index: 3,
+                merged_into: 1
+            })
+        );
+    }
+
+    #[test]
+    fn test_bulk_load_stable_with_invalid_vertex() {
+        let mut vertices = random_points_with_seed(10, SEED);
+        vertices.push(Point2::new(f64::NAN, 0.0));
+        vertices.push(vertices[0]);
+
+        let result: Result<DelaunayTriangulation<_>, _> = super::bulk_load_stable(vertices);
+        assert_eq!(
+            result.err(),
+            Some(StableBulkLoadError::InsertionError(InsertionError::NAN))
+        );
+    }
+
+    #[test]
+    fn test_insert_bulk() -> Result<(), InsertionError> {
+        let mut triangulation =
+            DelaunayTriangulation::<_>::bulk_load(random_points_with_seed(500, SEED))?;
+
+        let mut vertices = random_points_with_seed(1000, SEED2);
+        // Add some duplicates, including of already existing vertices
+        vertices.extend_from_within(100..200);
+        vertices.push(triangulation.vertices().next().unwrap().position());
+
+        let handles = triangulation.insert_bulk(vertices.clone())?;
+
+        triangulation.sanity_check();
+        assert_eq!(triangulation.num_vertices(), 1500);
+        assert_eq!(handles.len(), vertices.len());
+        for (handle, vertex) in handles.iter().zip(vertices) {
+            assert_eq!(triangulation.vertex(*handle).position(), vertex);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_insert_bulk_with_invalid_vertex() -> Result<(), InsertionError> {
+        let mut triangulation =
+            DelaunayTriangulation::<_>::bulk_load(random_points_with_seed(10, SEED))?;
+
+        let mut vertices = random_points_with_seed(10, SEED2);
+        vertices.push(Point2::new(f64::NAN, 0.0));
+        assert_eq!(
+            triangulation.insert_bulk(vertices),
+            Err(InsertionError::NAN)
+        );
+        assert_eq!(triangulation.num_vertices(), 10);
+        Ok(())
+    }
+
+    #[test]
+    fn test_hilbert_index() {
+        // The curve fills any aligned block of cells before leaving it. Consecutive cells
+        // along the curve are adjacent.
+        let mut cells = Vec::new();
+        for x in 0..8 {
+            for y in 0..8 {
+                cells.push((super::hilbert_index(x, y), x as i32, y as i32));
+            }
+        }
+        cells.sort_unstable();
+
+        for (index, (key, _, _)) in cells.iter().enumerate() {
+            assert_eq!(*key, index as u64);
+        }
+        for pair in cells.windows(2) {
+            let [(_, x0, y0), (_, x1, y1)] = [pair[0], pair[1]];
+            assert_eq!((x0 - x1).abs() + (y0 - y1).abs(), 1);
+        }
+    }
+
     #[test]
     fn test_hull() -> Result<(), InsertionError> {
         let mut triangulation = DelaunayTriangulation::<_>::new();
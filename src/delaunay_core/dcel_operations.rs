@@ -1045,6 +1045,169 @@ where
     }
 }
 
+/// Moves all elements of `other` into `dcel`.
+///
+/// The outer faces of both DCELs are merged. All vertices, edges and inner faces of `other` are
+/// appended, their handles are offset by the number of elements that were previously contained
+/// in `dcel`. The two DCELs remain disconnected.
+#[cfg(feature = "rayon")]
+pub fn append<V, DE, UE, F>(dcel: &mut Dcel<V, DE, UE, F>, other: Dcel<V, DE, UE, F>) {
+    let vertex_offset = dcel.num_vertices();
+    let directed_edge_offset = dcel.num_directed_edges();
+    // The outer face of other is skipped
+    let face_offset = dcel.num_faces() - 1;
+
+    let map_vertex =
+        |vertex: FixedVertexHandle| FixedVertexHandle::new(vertex.index() + vertex_offset);
+    let map_edge = |edge: FixedDirectedEdgeHandle| {
+        FixedDirectedEdgeHandle::new(edge.index() + directed_edge_offset)
+    };
+    let map_face = |face: FixedFaceHandle<PossiblyOuterTag>| {
+        if face == OUTER_FACE_HANDLE {
+            face
+        } else {
+            FixedFaceHandle::new(face.index() + face_offset)
+        }
+    };
+
+    let mut faces = other.faces.into_iter();
+    if let Some(outer_face) = faces.next() {
+        let outer_entry = &mut dcel.faces[OUTER_FACE_HANDLE.index()];
+        if outer_entry.adjacent_edge.is_none() {
+            outer_entry.adjacent_edge = outer_face.adjacent_edge.map(map_edge);
+        }
+    }
+
+    dcel.faces.extend(faces.map(|face| FaceEntry {
+        adjacent_edge: face.adjacent_edge.map(map_edge),
+        data: face.data,
+    }));
+
+    dcel.vertices
+        .extend(other.vertices.into_iter().map(|vertex| VertexEntry {
+            data: vertex.data,
+            out_edge: vertex.out_edge.map(map_edge),
+        }));
+
+    dcel.edges.extend(other.edges.into_iter().map(|edge| {
+        let [normalized, not_normalized] = edge.entries.map(|entry| HalfEdgeEntry {
+            next: map_edge(entry.next),
+            prev: map_edge(entry.prev),
+            face: map_face(entry.face),
+            origin: map_vertex(entry.origin),
+        });
+        EdgeEntry {
+            entries: [normalized, not_normalized],
+            directed_data: edge.directed_data,
+            undirected_data: edge.undirected_data,
+        }
+    }));
+}
+
+/// Connects the targets of two edges of the outer face with a new edge.
+///
+/// Both sides of the new edge will belong to the outer face. This can be used to connect two
+/// previously disconnected parts of a DCEL. The new edge will be inserted directly after `from`
+/// and `to` in their respective edge cycles.
+///
+/// Returns the new edge pointing from `from.to()` to `to.to()`.
+#[cfg(feature = "rayon")]
+pub fn connect_outer_edges<V, DE, UE, F>(
+    dcel: &mut Dcel<V, DE, UE, F>,
+    from: FixedDirectedEdgeHandle,
+    to: FixedDirectedEdgeHandle,
+) -> FixedDirectedEdgeHandle
+where
+    DE: Default,
+    UE: Default,
+{
+    //      from     new_edge      to
+    // ... ------> o ---------> o <------ ...
+    //             | <--------- ^
+    //             v   new_rev  |
+    //     from_next          to_next
+    let from_next = dcel.half_edge(from).next;
+    let to_next = dcel.half_edge(to).next;
+    let from_target = dcel.half_edge(from_next).origin;
+    let to_target = dcel.half_edge(to_next).origin;
+
+    let new_edge = FixedDirectedEdgeHandle::new_normalized(dcel.num_undirected_edges());
+    let new_rev = new_edge.rev();
+
+    let new_edge_entry = HalfEdgeEntry {
+        next: to_next,
+        prev: from,
+        face: OUTER_FACE_HANDLE,
+        origin: from_target,
+    };
+
+    let new_rev_entry = HalfEdgeEntry {
+        next: from_next,
+        prev: to,
+        face: OUTER_FACE_HANDLE,
+        origin: to_target,
+    };
+
+    dcel.edges
+        .push(EdgeEntry::new(new_edge_entry, new_rev_entry));
+
+    dcel.half_edge_mut(from).next = new_edge;
+    dcel.half_edge_mut(to_next).prev = new_edge;
+    dcel.half_edge_mut(to).next = new_rev;
+    dcel.half_edge_mut(from_next).prev = new_rev;
+
+    new_edge
+}
+
+/// Changes the order in which vertices are stored.
+///
+/// `new_indices` must be a permutation of all vertex handles. The vertex that was previously stored
+/// at index `i` will be moved to index `new_indices[i]`.
+pub fn reorder_vertices<V, DE, UE, F>(
+    dcel: &mut Dcel<V, DE, UE, F>,
+    new_indices: &[FixedVertexHandle],
+) {
+    assert_eq!(dcel.vertices.len(), new_indices.len());
+
+    let mut vertices: Vec<_> = core::mem::take(&mut dcel.vertices)
+        .into_iter()
+        .zip(new_indices.iter().copied())
+        .collect();
+    vertices.sort_unstable_by_key(|(_, new_index)| *new_index);
+    dcel.vertices = vertices.into_iter().map(|(vertex, _)| vertex).collect();
+
+    for edge in &mut dcel.edges {
+        for half_edge in &mut edge.entries {
+            half_edge.origin = new_indices[half_edge.origin.index()];
+        }
+    }
+}
+
+/// Swaps the storage location of two vertices.
+pub fn swap_vertices<V, DE, UE, F>(
+    dcel: &mut Dcel<V, DE, UE, F>,
+    v0: FixedVertexHandle,
+    v1: FixedVertexHandle,
+) {
+    let out_edges = |vertex| -> SmallVec<[_; 8]> {
+        dcel.vertex(vertex)
+            .out_edges()
+            .map(|edge| edge.fix())
+            .collect()
+    };
+    let out_edges0 = out_edges(v0);
+    let out_edges1 = out_edges(v1);
+
+    dcel.vertices.swap(v0.index(), v1.index());
+
+    for edge in out_edges0 {
+        dcel.half_edge_mut(edge).origin = v1;
+    }
+    for edge in out_edges1 {
+        dcel.half_edge_mut(edge).origin = v0;
+    }
+}
+
 /// Flip an edge in cw direction
 pub fn flip_cw<V, DE, UE, F>(dcel: &mut Dcel<V, DE, UE, F>, e: FixedUndirectedEdgeHandle) {
     let e = e.as_directed();
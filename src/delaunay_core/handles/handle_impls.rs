@@ -5,7 +5,7 @@ use super::iterators::CircularIterator;
 use super::iterators::NextBackFn;
 use super::public_handles::*;
 use crate::CdtEdge;
-use crate::{HasPosition, LineSideInfo, Point2};
+use crate::{HasPosition, HasWeight, LineSideInfo, Point2};
 use core::cmp::Ordering;
 use core::fmt::Debug;
 use core::hash::{Hash, Hasher};
@@ -432,11 +432,18 @@ where
     }
 }
 
-impl<'a, V, DE, UE, F> DirectedEdgeHandle<'a, V, DE, CdtEdge<UE>, F> {
+impl<'a, V, DE, UE, F, C> DirectedEdgeHandle<'a, V, DE, CdtEdge<UE, C>, F> {
     /// Returns `true` if this edge is a constraint edge.
     pub fn is_constraint_edge(self) -> bool {
         self.as_undirected().is_constraint_edge()
     }
+
+    /// Returns the id of the constraint this edge belongs to.
+    ///
+    /// *See [CdtEdge::constraint_id]*
+    pub fn constraint_id(self) -> Option<&'a C> {
+        self.as_undirected().constraint_id()
+    }
 }
 
 impl FixedUndirectedEdgeHandle {
@@ -570,11 +577,18 @@ where
     }
 }
 
-impl<'a, V, DE, UE, F> UndirectedEdgeHandle<'a, V, DE, CdtEdge<UE>, F> {
+impl<'a, V, DE, UE, F, C> UndirectedEdgeHandle<'a, V, DE, CdtEdge<UE, C>, F> {
     /// Returns `true` if this edge is a constraint edge.
     pub fn is_constraint_edge(self) -> bool {
         self.data().is_constraint_edge()
     }
+
+    /// Returns the id of the constraint this edge belongs to.
+    ///
+    /// *See [CdtEdge::constraint_id]*
+    pub fn constraint_id(self) -> Option<&'a C> {
+        self.dcel.undirected_edge_data(self.handle).constraint_id()
+    }
 }
 
 impl<'a, V, DE, UE, InnerOuter, F> AsRef<F> for FaceHandle<'a, InnerOuter, V, DE, UE, F>
@@ -687,6 +701,13 @@ where
         [lambda1, lambda2, lambda3]
     }
 
+    /// Returns the face's inner angles in radians.
+    ///
+    /// The angles are returned in the same order as the face's [vertices](Self::vertices).
+    pub fn angles(&self) -> [V::Scalar; 3] {
+        math::triangle_angles(self.positions())
+    }
+
     pub(crate) fn shortest_edge(&self) -> (DirectedEdgeHandle<'a, V, DE, UE, F>, V::Scalar) {
         let [e0, e1, e2] = self.adjacent_edges();
         let [l0, l1, l2] = [e0.length_2(), e1.length_2(), e2.length_2()];
@@ -700,6 +721,21 @@ where
     }
 }
 
+impl<'a, V, DE, UE, F> FaceHandle<'a, InnerTag, V, DE, UE, F>
+where
+    V: HasWeight,
+    V::Scalar: Float,
+{
+    /// Returns the face's power center.
+    ///
+    /// The power center has the same power distance to all three vertices. It is the center of
+    /// the face's orthogonal circle and equals the circumcenter if all weights are equal.
+    pub fn power_center(&self) -> Point2<V::Scalar> {
+        let weights = self.vertices().map(|vertex| vertex.data().weight());
+        math::power_center(self.positions(), weights)
+    }
+}
+
 impl<'a, V, DE, UE, F> AsRef<V> for VertexHandle<'a, V, DE, UE, F> {
     fn as_ref(&self) -> &V {
         self.data()
@@ -1,11 +1,16 @@
 use crate::{
-    delaunay_core::dcel_operations::{self},
-    HasPosition, Point2,
+    delaunay_core::{
+        dcel_operations::{self},
+        math,
+    },
+    HasPosition, HasWeight, Point2,
 };
 
+use alloc::vec::Vec;
+
 pub use super::handle_defs::*;
 
-use num_traits::Float;
+use num_traits::{zero, Float};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -269,6 +274,25 @@ where
     }
 }
 
+impl<'a, V, DE, UE, F> VoronoiVertex<'a, V, DE, UE, F>
+where
+    V: HasWeight,
+    V::Scalar: Float,
+{
+    /// The position of this vertex in a power diagram.
+    ///
+    /// Returns `None` if this vertex is an outer voronoi vertex.
+    /// Otherwise, the returned position is the
+    /// [power center](crate::handles::FaceHandle::power_center())
+    /// of the dual face of a [RegularTriangulation](crate::RegularTriangulation).
+    pub fn power_center(&self) -> Option<Point2<V::Scalar>> {
+        match self {
+            VoronoiVertex::Inner(face) => Some(face.power_center()),
+            VoronoiVertex::Outer(_) => None,
+        }
+    }
+}
+
 impl<'a, V, DE, UE, F> VoronoiFace<'a, V, DE, UE, F> {
     /// Converts this face into its dual vertex of the Delaunay Triangulation.
     pub fn as_delaunay_vertex(&self) -> VertexHandle<'a, V, DE, UE, F> {
@@ -287,6 +311,164 @@ impl<'a, V, DE, UE, F> VoronoiFace<'a, V, DE, UE, F> {
     }
 }
 
+impl<'a, V, DE, UE, F> VoronoiFace<'a, V, DE, UE, F>
+where
+    V: HasPosition,
+    V::Scalar: Float,
+{
+    /// Returns the part of this Voronoi cell that lies within a convex polygon.
+    ///
+    /// Unlike the faces of the Voronoi diagram, the result is always bounded. This works for
+    /// all cells, including the unbounded cells of vertices on the convex hull and cells of
+    /// triangulations whose vertices are all collinear.
+    ///
+    /// `clip_polygon` must be convex. Its vertices may be ordered clockwise or counterclockwise.
+    ///
+    /// The resulting polygon's vertices are ordered counterclockwise. The last vertex is
+    /// implicitly connected to the first vertex. An empty `Vec` is returned if the cell and
+    /// the clip polygon don't overlap.
+    ///
+    /// The calculation is not exact, cell boundaries are subject to rounding errors.
+    ///
+    /// The cell is bounded by the perpendicular bisectors to all neighbors, vertex weights are
+    /// ignored. Use [clip_power_cell_to_polygon](Self::clip_power_cell_to_polygon) to clip the
+    /// cells of a [power diagram](crate::RegularTriangulation#power-diagram) instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use spade::{DelaunayTriangulation, Point2, Triangulation};
+    /// # fn main() -> Result<(), spade::InsertionError> {
+    /// let triangulation = DelaunayTriangulation::<Point2<f64>>::bulk_load(vec![
+    ///     Point2::new(-1.0, 0.0),
+    ///     Point2::new(1.0, 0.0),
+    /// ])?;
+    ///
+    /// let triangle = [
+    ///     Point2::new(-4.0, -2.0),
+    ///     Point2::new(4.0, -2.0),
+    ///     Point2::new(0.0, 2.0),
+    /// ];
+    ///
+    /// for face in triangulation.voronoi_faces() {
+    ///     let cell = face.clip_to_polygon(&triangle);
+    ///     // Each cell contains one half of the triangle
+    ///     assert_eq!(cell.len(), 3);
+    /// }
+    /// # Ok(()) }
+    /// ```
+    pub fn clip_to_polygon(&self, clip_polygon: &[Point2<V::Scalar>]) -> Vec<Point2<V::Scalar>> {
+        self.clip_cell_to_polygon(clip_polygon, |_| zero())
+    }
+
+    fn clip_cell_to_polygon(
+        &self,
+        clip_polygon: &[Point2<V::Scalar>],
+        weight: impl Fn(&V) -> V::Scalar,
+    ) -> Vec<Point2<V::Scalar>> {
+        let mut result: Vec<_> = clip_polygon.to_vec();
+
+        let signed_area = |polygon: &[Point2<V::Scalar>]| {
+            let mut result: V::Scalar = zero();
+            for (index, from) in polygon.iter().enumerate() {
+                let to = polygon[(index + 1) % polygon.len()];
+                result = result + from.x * to.y - to.x * from.y;
+            }
+            result
+        };
+
+        if signed_area(&result) < zero() {
+            result.reverse();
+        }
+
+        let site = self.as_delaunay_vertex();
+        let weighted_site = (site.position(), weight(site.data()));
+        let mut buffer = Vec::with_capacity(result.len());
+        for edge in site.out_edges() {
+            if result.is_empty() {
+                break;
+            }
+            let other = edge.to();
+            let weighted_other = (other.position(), weight(other.data()));
+            math::clip_polygon_with_power_bisector(
+                &result,
+                weighted_site,
+                weighted_other,
+                &mut buffer,
+            );
+            core::mem::swap(&mut result, &mut buffer);
+        }
+
+        if result.len() < 3 {
+            // The cell only touches the clip polygon
+            result.clear();
+        }
+
+        result
+    }
+
+    /// Returns the part of this Voronoi cell that lies within an axis aligned rectangle.
+    ///
+    /// The rectangle is given by its lower left and upper right corner.
+    ///
+    /// See [clip_to_polygon](Self::clip_to_polygon) for more information.
+    pub fn clip_to_rectangle(
+        &self,
+        lower: Point2<V::Scalar>,
+        upper: Point2<V::Scalar>,
+    ) -> Vec<Point2<V::Scalar>> {
+        self.clip_to_polygon(&[
+            lower,
+            Point2::new(upper.x, lower.y),
+            upper,
+            Point2::new(lower.x, upper.y),
+        ])
+    }
+}
+
+impl<'a, V, DE, UE, F> VoronoiFace<'a, V, DE, UE, F>
+where
+    V: HasWeight,
+    V::Scalar: Float,
+{
+    /// Returns the part of this power diagram cell that lies within a convex polygon.
+    ///
+    /// This is the weighted equivalent of [clip_to_polygon](Self::clip_to_polygon) and should be
+    /// used for the faces of a [RegularTriangulation](crate::RegularTriangulation). The cell is
+    /// bounded by the power bisectors to all neighbors. Note that a power cell does not
+    /// necessarily contain its vertex.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use spade::{Point2, RegularTriangulation, Triangulation, WeightedPoint2};
+    /// # fn main() -> Result<(), spade::InsertionError> {
+    /// let mut triangulation = RegularTriangulation::<WeightedPoint2<f64>>::new();
+    /// triangulation.insert(WeightedPoint2::new(Point2::new(-1.0, 0.0), 2.0))?;
+    /// triangulation.insert(WeightedPoint2::new(Point2::new(1.0, 0.0), 0.0))?;
+    ///
+    /// let square = [
+    ///     Point2::new(-2.0, -2.0),
+    ///     Point2::new(2.0, -2.0),
+    ///     Point2::new(2.0, 2.0),
+    ///     Point2::new(-2.0, 2.0),
+    /// ];
+    ///
+    /// for face in triangulation.voronoi_faces() {
+    ///     let cell = face.clip_power_cell_to_polygon(&square);
+    ///     // The cells are separated by the line x = 0.5
+    ///     assert!(cell.iter().any(|point| point.x == 0.5));
+    /// }
+    /// # Ok(()) }
+    /// ```
+    pub fn clip_power_cell_to_polygon(
+        &self,
+        clip_polygon: &[Point2<V::Scalar>],
+    ) -> Vec<Point2<V::Scalar>> {
+        self.clip_cell_to_polygon(clip_polygon, |vertex| vertex.weight())
+    }
+}
+
 impl<'a, V, DE, UE, F> DirectedVoronoiEdge<'a, V, DE, UE, F> {
     /// Returns the voronoi edge's destination.
     pub fn to(&self) -> VoronoiVertex<'a, V, DE, UE, F> {
@@ -45,6 +45,17 @@ pub trait HintGenerator<S: SpadeNum>: Default {
         vertex_position: Point2<S>,
     );
 
+    /// Notifies the hint generator that a vertex was moved to a new position.
+    ///
+    /// The vertex handle remains unchanged. The default implementation does nothing.
+    fn notify_vertex_moved(
+        &mut self,
+        _vertex: FixedVertexHandle,
+        _old_position: Point2<S>,
+        _new_position: Point2<S>,
+    ) {
+    }
+
     /// Creates a new hint generator initialized to give hints for a specific triangulation
     fn initialize_from_triangulation<TR, V>(triangulation: &TR) -> Self
     where
@@ -115,6 +126,10 @@ impl<S: SpadeNum> HintGenerator<S> for LastUsedVertexHintGenerator {
         <Self as HintGenerator<S>>::notify_vertex_lookup(self, hint);
     }
 
+    fn notify_vertex_moved(&mut self, vertex: FixedVertexHandle, _: Point2<S>, _: Point2<S>) {
+        <Self as HintGenerator<S>>::notify_vertex_lookup(self, vertex);
+    }
+
     fn initialize_from_triangulation<TR, V>(_: &TR) -> Self
     where
         TR: Triangulation,
@@ -270,6 +285,26 @@ impl<S: SpadeNum, const BRANCH_FACTOR: u32> HintGenerator<S>
         }
     }
 
+    fn notify_vertex_moved(
+        &mut self,
+        vertex: FixedVertexHandle,
+        _old_position: Point2<S>,
+        new_position: Point2<S>,
+    ) {
+        let mut index = vertex.index() as u32;
+        for triangulation in &mut self.hierarchy {
+            let remainder = index % BRANCH_FACTOR;
+            index /= BRANCH_FACTOR;
+
+            if remainder != 0 {
+                break;
+            }
+            triangulation
+                .move_vertex(FixedVertexHandle::new(index as usize), new_position)
+                .unwrap();
+        }
+    }
+
     fn initialize_from_triangulation<TR, V>(triangulation: &TR) -> Self
     where
         TR: Triangulation<Vertex = V>,
@@ -372,4 +407,18 @@ mod test {
         }
         Ok(())
     }
+
+    #[test]
+    fn hierarchy_hint_generator_move_vertex_test() -> Result<(), InsertionError> {
+        let vertices = test_utilities::random_points_with_seed(300, test_utilities::SEED);
+        let mut triangulation = HierarchyTriangulation::bulk_load(vertices)?;
+
+        let new_positions = test_utilities::random_points_with_seed(300, test_utilities::SEED2);
+        for (index, new_position) in new_positions.into_iter().enumerate() {
+            triangulation.move_vertex(FixedVertexHandle::new(index), new_position)?;
+            hierarchy_sanity_check(&triangulation);
+        }
+        triangulation.sanity_check();
+        Ok(())
+    }
 }
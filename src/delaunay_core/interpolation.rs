@@ -2,9 +2,9 @@ use core::cell::RefCell;
 
 use crate::{
     delaunay_core::math,
-    handles::{FixedDirectedEdgeHandle, FixedVertexHandle},
-    DelaunayTriangulation, HasPosition, HintGenerator, Point2, PositionInTriangulation,
-    Triangulation,
+    handles::{DirectedEdgeHandle, FixedDirectedEdgeHandle, FixedVertexHandle},
+    DelaunayTriangulation, HasPosition, HintGenerator, Point2, PositionInTriangulation, SpadeNum,
+    Triangulation, TriangulationExt,
 };
 use num_traits::{one, zero, Float};
 
@@ -19,11 +19,15 @@ use super::VertexHandle;
 /// a country), natural neighbor interpolation allows to smoothly interpolate the associated value for every
 /// location within the convex hull of the input points.
 ///
-/// Spade currently assists with 4 interpolation strategies:
+/// Spade currently assists with 6 interpolation strategies:
 ///  - **Nearest neighbor interpolation:** Fastest. Exhibits too poor quality for many tasks. Not continuous
 ///    along the edges of the voronoi diagram. Use [DelaunayTriangulation::nearest_neighbor].
 ///  - **Barycentric interpolation:** Fast. Not smooth on the edges of the Delaunay triangulation.
 ///    See [Barycentric].
+///  - **Inverse distance weighting:** Weights the natural neighbors of a position by their inverse distance.
+///    Not smooth at the input points. See [InverseDistanceWeighting].
+///  - **Clough-Tocher interpolation:** Piecewise cubic. Smooth everywhere, requires a gradient at each
+///    input point. See [CloughTocher].
 ///  - **Natural neighbor interpolation:** Slower. Smooth everywhere except the input points. The input points
 ///    have no derivative. See [NaturalNeighbor::interpolate]
 ///  - **Natural neighbor interpolation with gradients:** Slowest. Smooth everywhere, even at the input points.
@@ -130,7 +134,203 @@ where
     inspect_edges_buffer: RefCell<Vec<FixedDirectedEdgeHandle>>,
     natural_neighbor_buffer: RefCell<Vec<FixedDirectedEdgeHandle>>,
     insert_cell_buffer: RefCell<Vec<Point2<<T::Vertex as HasPosition>::Scalar>>>,
+    // Only used for calculating the sibson coordinates of a vertex.
+    region_buffer: RefCell<Vec<Point2<<T::Vertex as HasPosition>::Scalar>>>,
+    clip_buffer: RefCell<Vec<Point2<<T::Vertex as HasPosition>::Scalar>>>,
     weight_buffer: RefCell<Vec<(FixedVertexHandle, <T::Vertex as HasPosition>::Scalar)>>,
+    extrapolation: Extrapolation,
+}
+
+/// A value that can be interpolated, e.g. a color or a vector.
+///
+/// Interpolating a value requires calculating a weighted sum of the values of several vertices.
+/// This trait is implemented for `f32`, `f64`, [Point2] and for arrays of any interpolatable type.
+/// `S` refers to the scalar type of the triangulation.
+///
+/// *See [NaturalNeighbor::interpolate_value] and [Barycentric::interpolate_value]*
+///
+/// # Example
+/// ```
+/// use spade::Interpolatable;
+///
+/// #[derive(Clone, Copy, Debug, PartialEq)]
+/// struct Rgb {
+///     r: f64,
+///     g: f64,
+///     b: f64,
+/// }
+///
+/// impl Interpolatable<f64> for Rgb {
+///     fn zero() -> Self {
+///         Rgb { r: 0.0, g: 0.0, b: 0.0 }
+///     }
+///
+///     fn add_weighted(self, value: Self, weight: f64) -> Self {
+///         Rgb {
+///             r: self.r + value.r * weight,
+///             g: self.g + value.g * weight,
+///             b: self.b + value.b * weight,
+///         }
+///     }
+/// }
+/// ```
+pub trait Interpolatable<S>: Sized {
+    /// Returns the value that is used as initial value of a weighted sum.
+    fn zero() -> Self;
+
+    /// Returns `self + value * weight`.
+    fn add_weighted(self, value: Self, weight: S) -> Self;
+}
+
+impl Interpolatable<f32> for f32 {
+    fn zero() -> Self {
+        0.0
+    }
+
+    fn add_weighted(self, value: Self, weight: f32) -> Self {
+        self + value * weight
+    }
+}
+
+impl Interpolatable<f64> for f64 {
+    fn zero() -> Self {
+        0.0
+    }
+
+    fn add_weighted(self, value: Self, weight: f64) -> Self {
+        self + value * weight
+    }
+}
+
+impl<S: SpadeNum> Interpolatable<S> for Point2<S> {
+    fn zero() -> Self {
+        Point2::new(S::zero(), S::zero())
+    }
+
+    fn add_weighted(self, value: Self, weight: S) -> Self {
+        self.add(value.mul(weight))
+    }
+}
+
+impl<S: Copy, T: Interpolatable<S>, const N: usize> Interpolatable<S> for [T; N] {
+    fn zero() -> Self {
+        core::array::from_fn(|_| T::zero())
+    }
+
+    fn add_weighted(self, value: Self, weight: S) -> Self {
+        let mut value = value.into_iter();
+        self.map(|element| element.add_weighted(value.next().unwrap(), weight))
+    }
+}
+
+/// A regular grid of query positions for interpolating many values at once.
+///
+/// The grid position in column `c` and row `r` is located at
+/// `origin + (c * spacing.x, r * spacing.y)`.
+///
+/// *See [NaturalNeighbor::interpolate_grid] and [Barycentric::interpolate_grid]*
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct InterpolationGrid<S> {
+    /// The position of the grid's first column and first row.
+    pub origin: Point2<S>,
+    /// The distance between two adjacent columns (`x`) and two adjacent rows (`y`).
+    pub spacing: Point2<S>,
+    /// The number of grid columns.
+    pub columns: usize,
+    /// The number of grid rows.
+    pub rows: usize,
+}
+
+impl<S: SpadeNum> InterpolationGrid<S> {
+    /// Creates a new grid.
+    pub fn new(origin: Point2<S>, spacing: Point2<S>, columns: usize, rows: usize) -> Self {
+        Self {
+            origin,
+            spacing,
+            columns,
+            rows,
+        }
+    }
+
+    /// Returns the number of grid positions (`columns * rows`).
+    pub fn num_positions(&self) -> usize {
+        self.columns * self.rows
+    }
+
+    /// Returns the position of a given grid column and row.
+    pub fn position(&self, column: usize, row: usize) -> Point2<S> {
+        let column: S = (column as f32).into();
+        let row: S = (row as f32).into();
+        Point2::new(
+            self.origin.x + column * self.spacing.x,
+            self.origin.y + row * self.spacing.y,
+        )
+    }
+}
+
+/// Defines how interpolation methods handle positions outside of the triangulation's convex hull.
+///
+/// By default, all interpolation methods return `None` for any position outside of the convex hull.
+/// Other extrapolation modes can be set with [Barycentric::with_extrapolation] and
+/// [NaturalNeighbor::with_extrapolation]. Extrapolation is only useful for positions slightly
+/// outside of the convex hull, e.g. to fill the corners of a rectangular terrain tile.
+///
+/// All modes, except for [Extrapolation::Disabled], start by identifying the convex hull edge that is
+/// closest to the query position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Extrapolation {
+    /// Positions outside of the convex hull are not interpolated.
+    #[default]
+    Disabled,
+
+    /// Projects the position onto the closest convex hull edge and interpolates linearly between the
+    /// edge's vertices.
+    ///
+    /// The resulting values are constant along lines perpendicular to the hull edge.
+    NearestHullEdge,
+
+    /// Extends the plane defined by the inner face adjacent to the closest convex hull edge.
+    ///
+    /// Some of the resulting weights will be negative. Use this mode with care: The extrapolated
+    /// values grow without bounds for positions far away from the convex hull.
+    NearestHullFace,
+
+    /// Uses the value of the vertex closest to the position.
+    ///
+    /// The closest vertex is only guaranteed to be found for a [DelaunayTriangulation]. For other
+    /// triangulations, e.g. a [ConstrainedDelaunayTriangulation](crate::ConstrainedDelaunayTriangulation),
+    /// the used vertex is close to the position but may not be the closest one.
+    NearestNeighbor,
+}
+
+/// Defines how [NaturalNeighbor::estimate_gradients] estimates the gradient at each vertex.
+///
+/// All methods reproduce the exact gradient if the interpolated values are taken from a linear
+/// function. They differ in how they weight the contribution of each neighbor.
+///
+/// *See [NaturalNeighbor::estimate_gradient]*
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum GradientEstimation {
+    /// Averages the normals of all adjacent faces, weighted by the face area.
+    ///
+    /// Each face defines a plane through the values of its three vertices. This is the fastest
+    /// method.
+    AreaWeightedFaceNormals,
+
+    /// Fits a plane through the vertex and all of its natural neighbors with a least squares
+    /// approximation.
+    ///
+    /// Each neighbor is weighted by its inverse squared distance to the vertex.
+    LeastSquares,
+
+    /// Sibson's method.
+    ///
+    /// Similar to [GradientEstimation::LeastSquares] but each neighbor's weight is additionally
+    /// multiplied by its natural neighbor coordinate (sibson coordinate) relative to the vertex.
+    /// This leads to less bias for irregularly spaced vertices. Vertices on the convex hull have
+    /// no bounded voronoi cell and fall back to [GradientEstimation::LeastSquares].
+    #[default]
+    Sibson,
 }
 
 /// Implements methods related to barycentric interpolation.
@@ -145,6 +345,74 @@ where
 {
     triangulation: &'a T,
     weight_buffer: RefCell<Vec<(FixedVertexHandle, <T::Vertex as HasPosition>::Scalar)>>,
+    extrapolation: Extrapolation,
+}
+
+/// Implements methods for inverse distance weighting (IDW) interpolation.
+///
+/// Inverse distance weighting interpolates a position by weighting the values of nearby vertices with the
+/// inverse of their distance to the position, raised to a given *power*. Only the natural neighbors of
+/// the position are taken into account. This keeps the interpolation local and fast.
+///
+/// Created by calling [DelaunayTriangulation::inverse_distance_weighting]. Similar to [NaturalNeighbor],
+/// this type contains internal buffers and should be re-used for many interpolations.
+///
+/// Refer to the documentation of [NaturalNeighbor] for an overview of different interpolation methods.
+///
+/// # Example
+/// ```
+/// use spade::{DelaunayTriangulation, Point2, Triangulation};
+/// # fn main() -> Result<(), spade::InsertionError> {
+/// let triangulation = DelaunayTriangulation::<Point2<f64>>::bulk_load(vec![
+///     Point2::new(0.0, 0.0),
+///     Point2::new(2.0, 0.0),
+///     Point2::new(0.0, 2.0),
+///     Point2::new(2.0, 2.0),
+/// ])?;
+///
+/// let idw = triangulation.inverse_distance_weighting().with_power(3.0);
+/// let value = idw.interpolate(|v| v.position().x, Point2::new(1.0, 1.0));
+/// assert_eq!(value, Some(1.0));
+/// # Ok(()) }
+/// ```
+#[doc(alias = "Interpolation")]
+#[doc(alias = "IDW")]
+pub struct InverseDistanceWeighting<'a, T>
+where
+    T: Triangulation,
+{
+    triangulation: &'a T,
+    power: <T::Vertex as HasPosition>::Scalar,
+    inspect_edges_buffer: RefCell<Vec<FixedDirectedEdgeHandle>>,
+    natural_neighbor_buffer: RefCell<Vec<FixedDirectedEdgeHandle>>,
+    weight_buffer: RefCell<Vec<(FixedVertexHandle, <T::Vertex as HasPosition>::Scalar)>>,
+}
+
+/// Implements methods for Clough-Tocher interpolation.
+///
+/// Clough-Tocher interpolation splits each triangle into three sub triangles at its centroid and
+/// interpolates a cubic polynomial on each of them. The resulting surface is smooth (C1 continuous)
+/// everywhere, including the input vertices and the edges of the triangulation. The interpolation
+/// requires a gradient at each vertex, see [NaturalNeighbor::estimate_gradients] for estimating them.
+///
+/// In contrast to [NaturalNeighbor::interpolate_gradient], Clough-Tocher interpolation only depends on the
+/// three vertices of the face containing the query position. It is faster and also works for
+/// [crate::ConstrainedDelaunayTriangulation]s.
+///
+/// Created by calling [crate::FloatTriangulation::clough_tocher].
+///
+/// Refer to the documentation of [NaturalNeighbor] for an overview of different interpolation methods.
+///
+/// # References
+///
+/// This implementation chooses the cross boundary derivative to be linear along each edge as described in
+/// "A triangular cubic spline patch, G. Farin, 1986".
+#[doc(alias = "Interpolation")]
+pub struct CloughTocher<'a, T>
+where
+    T: Triangulation,
+{
+    triangulation: &'a T,
 }
 
 impl<'a, T> Barycentric<'a, T>
@@ -156,16 +424,45 @@ where
         Self {
             triangulation,
             weight_buffer: Default::default(),
+            extrapolation: Extrapolation::Disabled,
         }
     }
 
+    /// Defines how positions outside of the triangulation's convex hull are interpolated.
+    ///
+    /// Defaults to [Extrapolation::Disabled].
+    ///
+    /// # Example
+    /// ```
+    /// use spade::{DelaunayTriangulation, Extrapolation, FloatTriangulation, Point2, Triangulation};
+    /// # fn main() -> Result<(), spade::InsertionError> {
+    /// let triangulation = DelaunayTriangulation::<Point2<f64>>::bulk_load(vec![
+    ///     Point2::new(0.0, 0.0),
+    ///     Point2::new(1.0, 0.0),
+    ///     Point2::new(0.0, 1.0),
+    /// ])?;
+    ///
+    /// let outside = Point2::new(-1.0, 0.5);
+    /// let barycentric = triangulation.barycentric();
+    /// assert_eq!(barycentric.interpolate(|v| v.position().y, outside), None);
+    ///
+    /// let barycentric = barycentric.with_extrapolation(Extrapolation::NearestHullEdge);
+    /// assert_eq!(barycentric.interpolate(|v| v.position().y, outside), Some(0.5));
+    /// # Ok(()) }
+    /// ```
+    pub fn with_extrapolation(mut self, extrapolation: Extrapolation) -> Self {
+        self.extrapolation = extrapolation;
+        self
+    }
+
     /// Returns the barycentric coordinates and the respective vertices for a given query position.
     ///
     /// The resulting coordinates and vertices are stored within the given `result` `vec`` to prevent
     /// unneeded allocations. `result` will be cleared initially.
     ///
     /// The number of returned elements depends on the query positions location:
-    ///  - `result` will be **empty** if the query position lies outside of the triangulation's convex hull
+    ///  - `result` will be **empty** if the query position lies outside of the triangulation's convex hull.
+    ///    This does not apply if an [Extrapolation] mode has been set.
     ///  - `result` will contain **a single element** (with weight 1.0) if the query position lies exactly on a vertex
     ///  - `result` will contain **two vertices** if the query point lies exactly on any edge of the triangulation.
     ///  - `result` will contain **exactly three** elements if the query point lies on an inner face of the
@@ -174,9 +471,18 @@ where
         &self,
         position: Point2<<T::Vertex as HasPosition>::Scalar>,
         result: &mut Vec<(FixedVertexHandle, <T::Vertex as HasPosition>::Scalar)>,
+    ) {
+        self.get_weights_with_hint(position, &mut None, result);
+    }
+
+    fn get_weights_with_hint(
+        &self,
+        position: Point2<<T::Vertex as HasPosition>::Scalar>,
+        hint: &mut Option<FixedVertexHandle>,
+        result: &mut Vec<(FixedVertexHandle, <T::Vertex as HasPosition>::Scalar)>,
     ) {
         result.clear();
-        match self.triangulation.locate(position) {
+        match locate_and_update_hint(self.triangulation, position, hint) {
             PositionInTriangulation::OnVertex(vertex) => {
                 result.push((vertex, <T::Vertex as HasPosition>::Scalar::from(1.0)))
             }
@@ -192,13 +498,20 @@ where
                 let [c0, c1, c2] = face.barycentric_interpolation(position);
                 result.extend([(v0.fix(), c0), (v1.fix(), c1), (v2.fix(), c2)]);
             }
-            _ => {}
+            location => get_extrapolation_weights(
+                self.triangulation,
+                self.extrapolation,
+                location,
+                position,
+                result,
+            ),
         }
     }
 
     /// Performs barycentric interpolation on this triangulation at a given position.
     ///
-    /// Returns `None` for any value outside the triangulation's convex hull.
+    /// Returns `None` for any value outside the triangulation's convex hull unless an [Extrapolation] mode
+    /// has been set.
     /// The value to interpolate is given by the `i` parameter.
     ///
     /// Refer to [NaturalNeighbor] for a comparison with other interpolation methods.
@@ -211,9 +524,153 @@ where
         I: Fn(
             VertexHandle<T::Vertex, T::DirectedEdge, T::UndirectedEdge, T::Face>,
         ) -> <T::Vertex as HasPosition>::Scalar,
+    {
+        self.interpolate_with_hint(&i, position, &mut None)
+    }
+
+    /// Performs barycentric interpolation of an arbitrary [Interpolatable] value, e.g. a color or a vector.
+    ///
+    /// In contrast to calling [Self::interpolate] once per component, the interpolation weights are
+    /// only calculated once.
+    ///
+    /// Returns `None` for any value outside the triangulation's convex hull unless an [Extrapolation] mode
+    /// has been set.
+    ///
+    /// # Example
+    /// ```
+    /// use spade::{DelaunayTriangulation, FloatTriangulation, Point2, Triangulation};
+    /// # fn main() -> Result<(), spade::InsertionError> {
+    /// let triangulation = DelaunayTriangulation::<Point2<f64>>::bulk_load(vec![
+    ///     Point2::new(0.0, 0.0),
+    ///     Point2::new(1.0, 0.0),
+    ///     Point2::new(0.0, 1.0),
+    /// ])?;
+    ///
+    /// // Interpolates a 3 component vector at once
+    /// let value = triangulation
+    ///     .barycentric()
+    ///     .interpolate_value(|v| [v.position().x, v.position().y, 1.0], Point2::new(0.25, 0.5));
+    /// assert_eq!(value, Some([0.25, 0.5, 1.0]));
+    /// # Ok(()) }
+    /// ```
+    pub fn interpolate_value<R, I>(
+        &self,
+        i: I,
+        position: Point2<<T::Vertex as HasPosition>::Scalar>,
+    ) -> Option<R>
+    where
+        R: Interpolatable<<T::Vertex as HasPosition>::Scalar>,
+        I: Fn(VertexHandle<T::Vertex, T::DirectedEdge, T::UndirectedEdge, T::Face>) -> R,
     {
         let nns = &mut *self.weight_buffer.borrow_mut();
         self.get_weights(position, nns);
+        weighted_sum(self.triangulation, nns, i)
+    }
+
+    /// Performs barycentric interpolation for many query positions at once.
+    ///
+    /// This is faster than calling [Self::interpolate] for every position if subsequent positions
+    /// lie close to each other: The position lookup always starts at the last found position.
+    ///
+    /// The interpolated values are stored within `result` which will be cleared initially. Each value
+    /// is `None` if its position lies outside of the convex hull, similar to [Self::interpolate].
+    /// Any [Interpolatable] value can be interpolated, see [Self::interpolate_value].
+    pub fn interpolate_points<R, I>(
+        &self,
+        i: I,
+        positions: &[Point2<<T::Vertex as HasPosition>::Scalar>],
+        result: &mut Vec<Option<R>>,
+    ) where
+        R: Interpolatable<<T::Vertex as HasPosition>::Scalar>,
+        I: Fn(VertexHandle<T::Vertex, T::DirectedEdge, T::UndirectedEdge, T::Face>) -> R,
+    {
+        let mut hint = None;
+        result.clear();
+        result.extend(
+            positions
+                .iter()
+                .map(|position| self.interpolate_value_with_hint(&i, *position, &mut hint)),
+        );
+    }
+
+    /// Performs barycentric interpolation for all positions of a regular grid.
+    ///
+    /// The grid is traversed row by row and the position lookup always starts at the last found position.
+    /// This is considerably faster than calling [Self::interpolate] for every grid position.
+    ///
+    /// The interpolated values are stored within `result` which will be cleared initially. The value of
+    /// a grid position can be looked up with `result[row * grid.columns + column]`. Any [Interpolatable]
+    /// value can be interpolated, see [Self::interpolate_value].
+    ///
+    /// *See also [InterpolationGrid]*
+    pub fn interpolate_grid<R, I>(
+        &self,
+        i: I,
+        grid: &InterpolationGrid<<T::Vertex as HasPosition>::Scalar>,
+        result: &mut Vec<Option<R>>,
+    ) where
+        R: Interpolatable<<T::Vertex as HasPosition>::Scalar>,
+        I: Fn(VertexHandle<T::Vertex, T::DirectedEdge, T::UndirectedEdge, T::Face>) -> R,
+    {
+        result.clear();
+        result.resize_with(grid.num_positions(), || None);
+        interpolate_grid_rows(grid, 0, result, |position, hint| {
+            self.interpolate_value_with_hint(&i, position, hint)
+        });
+    }
+
+    /// Same as [Self::interpolate_grid] but distributes the grid's rows across multiple threads.
+    ///
+    /// Requires the `rayon` feature.
+    #[cfg(feature = "rayon")]
+    pub fn interpolate_grid_parallel<R, I>(
+        &self,
+        i: I,
+        grid: &InterpolationGrid<<T::Vertex as HasPosition>::Scalar>,
+        result: &mut Vec<Option<R>>,
+    ) where
+        T: Sync,
+        <T::Vertex as HasPosition>::Scalar: Send + Sync,
+        R: Interpolatable<<T::Vertex as HasPosition>::Scalar> + Send,
+        I: Fn(VertexHandle<T::Vertex, T::DirectedEdge, T::UndirectedEdge, T::Face>) -> R + Sync,
+    {
+        use rayon::prelude::*;
+
+        let triangulation = self.triangulation;
+        let extrapolation = self.extrapolation;
+
+        result.clear();
+        result.resize_with(grid.num_positions(), || None);
+        if grid.columns == 0 {
+            return;
+        }
+
+        result
+            .par_chunks_mut(grid.columns)
+            .enumerate()
+            .for_each_init(
+                || Barycentric::new(triangulation).with_extrapolation(extrapolation),
+                |barycentric, (row, row_result)| {
+                    interpolate_grid_rows(grid, row, row_result, |position, hint| {
+                        barycentric.interpolate_value_with_hint(&i, position, hint)
+                    });
+                },
+            );
+    }
+
+    fn interpolate_with_hint<I>(
+        &self,
+        i: &I,
+        position: Point2<<T::Vertex as HasPosition>::Scalar>,
+        hint: &mut Option<FixedVertexHandle>,
+    ) -> Option<<T::Vertex as HasPosition>::Scalar>
+    where
+        I: Fn(
+            VertexHandle<T::Vertex, T::DirectedEdge, T::UndirectedEdge, T::Face>,
+        ) -> <T::Vertex as HasPosition>::Scalar,
+    {
+        let nns = &mut *self.weight_buffer.borrow_mut();
+        self.get_weights_with_hint(position, hint, nns);
         if nns.is_empty() {
             return None;
         }
@@ -224,6 +681,21 @@ where
         }
         Some(total_sum)
     }
+
+    fn interpolate_value_with_hint<R, I>(
+        &self,
+        i: &I,
+        position: Point2<<T::Vertex as HasPosition>::Scalar>,
+        hint: &mut Option<FixedVertexHandle>,
+    ) -> Option<R>
+    where
+        R: Interpolatable<<T::Vertex as HasPosition>::Scalar>,
+        I: Fn(VertexHandle<T::Vertex, T::DirectedEdge, T::UndirectedEdge, T::Face>) -> R,
+    {
+        let nns = &mut *self.weight_buffer.borrow_mut();
+        self.get_weights_with_hint(position, hint, nns);
+        weighted_sum(self.triangulation, nns, i)
+    }
 }
 
 impl<'a, V, DE, UE, F, L> NaturalNeighbor<'a, DelaunayTriangulation<V, DE, UE, F, L>>
@@ -240,11 +712,24 @@ where
             triangulation,
             inspect_edges_buffer: Default::default(),
             insert_cell_buffer: Default::default(),
+            region_buffer: Default::default(),
+            clip_buffer: Default::default(),
             natural_neighbor_buffer: Default::default(),
             weight_buffer: Default::default(),
+            extrapolation: Extrapolation::Disabled,
         }
     }
 
+    /// Defines how positions outside of the triangulation's convex hull are interpolated.
+    ///
+    /// Defaults to [Extrapolation::Disabled]. Outside of the convex hull, the natural neighbor weights
+    /// are replaced by the weights of the chosen [Extrapolation] mode. The interpolation will
+    /// hence not be smooth along the convex hull.
+    pub fn with_extrapolation(mut self, extrapolation: Extrapolation) -> Self {
+        self.extrapolation = extrapolation;
+        self
+    }
+
     /// Calculates the natural neighbors and their weights (sibson coordinates) of a given query position.
     ///
     /// The neighbors are returned in clockwise order. The weights will add up to 1.0.
@@ -252,7 +737,8 @@ where
     /// `result` will be cleared initially.
     ///
     /// The number of returned natural neighbors depends on the given query position:
-    /// - `result` will be **empty** if the query position lies outside of the triangulation's convex hull
+    /// - `result` will be **empty** if the query position lies outside of the triangulation's convex hull.
+    ///    This does not apply if an [Extrapolation] mode has been set.
     /// - `result` will contain **exactly one** vertex if the query position is equal to that vertex position.
     /// - `result` will contain **exactly two** entries if the query position lies exactly *on* an edge of the
     ///    convex hull.
@@ -267,20 +753,40 @@ where
         &self,
         position: Point2<<V as HasPosition>::Scalar>,
         result: &mut Vec<(FixedVertexHandle, <V as HasPosition>::Scalar)>,
+    ) {
+        self.get_weights_with_hint(position, &mut None, result);
+    }
+
+    fn get_weights_with_hint(
+        &self,
+        position: Point2<<V as HasPosition>::Scalar>,
+        hint: &mut Option<FixedVertexHandle>,
+        result: &mut Vec<(FixedVertexHandle, <V as HasPosition>::Scalar)>,
     ) {
         let nns = &mut *self.natural_neighbor_buffer.borrow_mut();
-        get_natural_neighbor_edges(
+        let location = get_natural_neighbor_edges(
             self.triangulation,
             &mut self.inspect_edges_buffer.borrow_mut(),
             position,
+            hint,
             nns,
         );
         self.get_natural_neighbor_weights(position, nns, result);
+        if result.is_empty() {
+            get_extrapolation_weights(
+                self.triangulation,
+                self.extrapolation,
+                location,
+                position,
+                result,
+            );
+        }
     }
 
     /// Interpolates a value at a given position.
     ///
-    /// Returns `None` for any point outside the triangulations convex hull.
+    /// Returns `None` for any point outside the triangulations convex hull unless an [Extrapolation] mode has
+    /// been set.
     /// The value to interpolate is given by the `i` parameter. The resulting interpolation will be smooth
     /// everywhere except at the input vertices.
     ///
@@ -290,11 +796,188 @@ where
         i: I,
         position: Point2<<V as HasPosition>::Scalar>,
     ) -> Option<<V as HasPosition>::Scalar>
+    where
+        I: Fn(VertexHandle<V, DE, UE, F>) -> <V as HasPosition>::Scalar,
+    {
+        self.interpolate_with_hint(&i, position, &mut None)
+    }
+
+    /// Interpolates an arbitrary [Interpolatable] value at a given position, e.g. a color or a vector.
+    ///
+    /// In contrast to calling [Self::interpolate] once per component, the natural neighbor weights are
+    /// only calculated once.
+    ///
+    /// Returns `None` for any point outside the triangulations convex hull unless an [Extrapolation] mode has
+    /// been set.
+    ///
+    /// # Example
+    /// ```
+    /// use spade::{DelaunayTriangulation, HasPosition, Point2, Triangulation};
+    ///
+    /// struct WindMeasurement {
+    ///     position: Point2<f64>,
+    ///     wind: Point2<f64>,
+    /// }
+    ///
+    /// impl HasPosition for WindMeasurement {
+    ///     type Scalar = f64;
+    ///     fn position(&self) -> Point2<f64> { self.position }
+    /// }
+    ///
+    /// # fn main() -> Result<(), spade::InsertionError> {
+    /// let mut triangulation = DelaunayTriangulation::<WindMeasurement>::new();
+    /// for (x, y) in [(0.0, 0.0), (1.0, 0.0), (0.0, 1.0), (1.0, 1.0)] {
+    ///     let position = Point2::new(x, y);
+    ///     triangulation.insert(WindMeasurement { position, wind: Point2::new(2.0, -1.0) })?;
+    /// }
+    ///
+    /// let nn = triangulation.natural_neighbor();
+    /// let wind = nn.interpolate_value(|v| v.data().wind, Point2::new(0.3, 0.6)).unwrap();
+    /// assert!((wind.x - 2.0).abs() < 1e-10);
+    /// assert!((wind.y + 1.0).abs() < 1e-10);
+    /// # Ok(()) }
+    /// ```
+    pub fn interpolate_value<R, I>(
+        &self,
+        i: I,
+        position: Point2<<V as HasPosition>::Scalar>,
+    ) -> Option<R>
+    where
+        R: Interpolatable<<V as HasPosition>::Scalar>,
+        I: Fn(VertexHandle<V, DE, UE, F>) -> R,
+    {
+        let nns = &mut *self.weight_buffer.borrow_mut();
+        self.get_weights(position, nns);
+        weighted_sum(self.triangulation, nns, i)
+    }
+
+    /// Interpolates values for many query positions at once.
+    ///
+    /// This is faster than calling [Self::interpolate] for every position if subsequent positions
+    /// lie close to each other: The position lookup always starts at the last found position.
+    ///
+    /// The interpolated values are stored within `result` which will be cleared initially. Each value
+    /// is `None` if its position lies outside of the convex hull, similar to [Self::interpolate].
+    /// Any [Interpolatable] value can be interpolated, see [Self::interpolate_value].
+    pub fn interpolate_points<R, I>(
+        &self,
+        i: I,
+        positions: &[Point2<<V as HasPosition>::Scalar>],
+        result: &mut Vec<Option<R>>,
+    ) where
+        R: Interpolatable<<V as HasPosition>::Scalar>,
+        I: Fn(VertexHandle<V, DE, UE, F>) -> R,
+    {
+        let mut hint = None;
+        result.clear();
+        result.extend(
+            positions
+                .iter()
+                .map(|position| self.interpolate_value_with_hint(&i, *position, &mut hint)),
+        );
+    }
+
+    /// Interpolates values for all positions of a regular grid, e.g. for rasterizing a digital
+    /// elevation model.
+    ///
+    /// The grid is traversed row by row and the position lookup always starts at the last found position.
+    /// This is considerably faster than calling [Self::interpolate] for every grid position.
+    ///
+    /// The interpolated values are stored within `result` which will be cleared initially. The value of
+    /// a grid position can be looked up with `result[row * grid.columns + column]`. Any [Interpolatable]
+    /// value can be interpolated, see [Self::interpolate_value].
+    ///
+    /// # Example
+    /// ```
+    /// use spade::{DelaunayTriangulation, InterpolationGrid, Point2, Triangulation};
+    /// # fn main() -> Result<(), spade::InsertionError> {
+    /// let triangulation = DelaunayTriangulation::<Point2<f64>>::bulk_load(vec![
+    ///     Point2::new(0.0, 0.0),
+    ///     Point2::new(10.0, 0.0),
+    ///     Point2::new(0.0, 10.0),
+    ///     Point2::new(10.0, 10.0),
+    /// ])?;
+    ///
+    /// // 11 x 6 grid positions with a spacing of 1.0 and 2.0
+    /// let grid = InterpolationGrid::new(Point2::new(0.0, 0.0), Point2::new(1.0, 2.0), 11, 6);
+    /// let mut heights = Vec::new();
+    /// triangulation
+    ///     .natural_neighbor()
+    ///     .interpolate_grid(|v| v.position().x, &grid, &mut heights);
+    ///
+    /// assert_eq!(heights.len(), 66);
+    /// // Value at row 2, column 3
+    /// assert!((heights[2 * grid.columns + 3].unwrap() - 3.0).abs() < 1e-10);
+    /// # Ok(()) }
+    /// ```
+    pub fn interpolate_grid<R, I>(
+        &self,
+        i: I,
+        grid: &InterpolationGrid<<V as HasPosition>::Scalar>,
+        result: &mut Vec<Option<R>>,
+    ) where
+        R: Interpolatable<<V as HasPosition>::Scalar>,
+        I: Fn(VertexHandle<V, DE, UE, F>) -> R,
+    {
+        result.clear();
+        result.resize_with(grid.num_positions(), || None);
+        interpolate_grid_rows(grid, 0, result, |position, hint| {
+            self.interpolate_value_with_hint(&i, position, hint)
+        });
+    }
+
+    /// Same as [Self::interpolate_grid] but distributes the grid's rows across multiple threads.
+    ///
+    /// Each thread uses its own [NaturalNeighbor] instance with the same [Extrapolation] mode.
+    ///
+    /// Requires the `rayon` feature.
+    #[cfg(feature = "rayon")]
+    pub fn interpolate_grid_parallel<R, I>(
+        &self,
+        i: I,
+        grid: &InterpolationGrid<<V as HasPosition>::Scalar>,
+        result: &mut Vec<Option<R>>,
+    ) where
+        DelaunayTriangulation<V, DE, UE, F, L>: Sync,
+        <V as HasPosition>::Scalar: Send + Sync,
+        R: Interpolatable<<V as HasPosition>::Scalar> + Send,
+        I: Fn(VertexHandle<V, DE, UE, F>) -> R + Sync,
+    {
+        use rayon::prelude::*;
+
+        let triangulation = self.triangulation;
+        let extrapolation = self.extrapolation;
+
+        result.clear();
+        result.resize_with(grid.num_positions(), || None);
+        if grid.columns == 0 {
+            return;
+        }
+
+        result
+            .par_chunks_mut(grid.columns)
+            .enumerate()
+            .for_each_init(
+                || NaturalNeighbor::new(triangulation).with_extrapolation(extrapolation),
+                |nn, (row, row_result)| {
+                    interpolate_grid_rows(grid, row, row_result, |position, hint| {
+                        nn.interpolate_value_with_hint(&i, position, hint)
+                    });
+                },
+            );
+    }
+
+    fn interpolate_with_hint<I>(
+        &self,
+        i: &I,
+        position: Point2<<V as HasPosition>::Scalar>,
+        hint: &mut Option<FixedVertexHandle>,
+    ) -> Option<<V as HasPosition>::Scalar>
     where
         I: Fn(VertexHandle<V, DE, UE, F>) -> <V as HasPosition>::Scalar,
     {
         let nns = &mut *self.weight_buffer.borrow_mut();
-        self.get_weights(position, nns);
+        self.get_weights_with_hint(position, hint, nns);
         if nns.is_empty() {
             return None;
         }
@@ -306,19 +989,36 @@ where
         Some(total_sum)
     }
 
+    fn interpolate_value_with_hint<R, I>(
+        &self,
+        i: &I,
+        position: Point2<<V as HasPosition>::Scalar>,
+        hint: &mut Option<FixedVertexHandle>,
+    ) -> Option<R>
+    where
+        R: Interpolatable<<V as HasPosition>::Scalar>,
+        I: Fn(VertexHandle<V, DE, UE, F>) -> R,
+    {
+        let nns = &mut *self.weight_buffer.borrow_mut();
+        self.get_weights_with_hint(position, hint, nns);
+        weighted_sum(self.triangulation, nns, i)
+    }
+
     /// Interpolates a value at a given position.
     ///
     /// In contrast to [Self::interpolate], this method has a well defined derivative at each vertex and will
     /// approximate a linear function in the proximity of any vertex.
     ///
     /// The value to interpolate is given by the `i` parameter. The gradient that defines the derivative at
-    /// each input vertex is given by the `g` parameter.
+    /// each input vertex is given by the `g` parameter. Use [Self::estimate_gradients] if the gradients are
+    /// not known.
     ///
     /// The `flatness` parameter blends between an interpolation that ignores the given gradients (value 0.0)
     /// or adheres to it strongly (values larger than ~2.0) in the vicinity of any vertex. When in doubt, using
     /// a value of 1.0 should result in a good interpolation and is also the fastest.
     ///
-    /// Returns `None` for any point outside of the triangulation's convex hull.
+    /// Returns `None` for any point outside of the triangulation's convex hull unless an [Extrapolation] mode
+    /// has been set.
     ///
     /// Refer to [NaturalNeighbor] for more information and a visual example.
     ///
@@ -395,7 +1095,7 @@ where
             let r_i = r_i2.powf(flatness);
             let c1_weight_i = *weight / r_i;
             let grad_i = g(handle);
-            let zeta_i = h_i + diff.dot(grad_i.into());
+            let zeta_i = h_i - diff.dot(grad_i.into());
             alpha = alpha + c1_weight_i * r_i;
             beta = beta + c1_weight_i * r_i2;
             sum_c1_weights = sum_c1_weights + c1_weight_i;
@@ -409,6 +1109,101 @@ where
         Some(result)
     }
 
+    /// Estimates the gradient of an interpolated value at a single vertex.
+    ///
+    /// The value at each vertex is given by the `i` parameter, `method` defines the estimation
+    /// method. Returns a gradient of `[0.0, 0.0]` if the vertex has no neighbors or if all vertices
+    /// are collinear.
+    ///
+    /// Use [Self::estimate_gradients] to estimate the gradients of all vertices at once.
+    pub fn estimate_gradient<I>(
+        &self,
+        i: I,
+        vertex: FixedVertexHandle,
+        method: GradientEstimation,
+    ) -> [<V as HasPosition>::Scalar; 2]
+    where
+        I: Fn(VertexHandle<V, DE, UE, F>) -> <V as HasPosition>::Scalar,
+    {
+        let vertex = self.triangulation.vertex(vertex);
+        match method {
+            GradientEstimation::AreaWeightedFaceNormals => estimate_gradient_from_faces(vertex, &i),
+            GradientEstimation::LeastSquares => {
+                estimate_gradient_least_squares(vertex, &i, |_, _| one())
+            }
+            GradientEstimation::Sibson => {
+                let weights = &mut *self.weight_buffer.borrow_mut();
+                let buffers = [
+                    &mut *self.insert_cell_buffer.borrow_mut(),
+                    &mut *self.region_buffer.borrow_mut(),
+                    &mut *self.clip_buffer.borrow_mut(),
+                ];
+                if !get_vertex_sibson_coordinates(vertex, buffers, weights) {
+                    return estimate_gradient_least_squares(vertex, &i, |_, _| one());
+                }
+
+                estimate_gradient_least_squares(vertex, &i, |index, _| weights[index].1)
+            }
+        }
+    }
+
+    /// Estimates the gradient of an interpolated value at every vertex of the triangulation.
+    ///
+    /// The value at each vertex is given by the `i` parameter. The resulting gradients can be
+    /// looked up with `result[vertex.index()]` which makes them suitable for
+    /// [Self::interpolate_gradient].
+    ///
+    /// Refer to [GradientEstimation] for a description of the available estimation methods.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use spade::{DelaunayTriangulation, GradientEstimation, HasPosition, Point2, Triangulation};
+    ///
+    /// struct PointWithHeight {
+    ///     position: Point2<f64>,
+    ///     height: f64,
+    /// }
+    ///
+    /// impl HasPosition for PointWithHeight {
+    ///     type Scalar = f64;
+    ///     fn position(&self) -> Point2<f64> { self.position }
+    /// }
+    ///
+    /// # fn main() -> Result<(), spade::InsertionError> {
+    /// let mut triangulation = DelaunayTriangulation::<PointWithHeight>::new();
+    /// for (x, y) in [(0.0, 0.0), (4.0, 0.0), (0.0, 4.0), (4.0, 4.0), (1.0, 2.0)] {
+    ///     // Height is given by a linear function
+    ///     let height = 2.0 * x - y;
+    ///     triangulation.insert(PointWithHeight { position: Point2::new(x, y), height })?;
+    /// }
+    ///
+    /// let nn = triangulation.natural_neighbor();
+    /// let gradients = nn.estimate_gradients(|v| v.data().height, GradientEstimation::Sibson);
+    ///
+    /// let value = nn.interpolate_gradient(
+    ///     |v| v.data().height,
+    ///     |v| gradients[v.index()],
+    ///     1.0,
+    ///     Point2::new(2.0, 1.0),
+    /// );
+    /// assert!((value.unwrap() - 3.0).abs() < 1e-10);
+    /// # Ok(()) }
+    /// ```
+    pub fn estimate_gradients<I>(
+        &self,
+        i: I,
+        method: GradientEstimation,
+    ) -> Vec<[<V as HasPosition>::Scalar; 2]>
+    where
+        I: Fn(VertexHandle<V, DE, UE, F>) -> <V as HasPosition>::Scalar,
+    {
+        self.triangulation
+            .fixed_vertices()
+            .map(|vertex| self.estimate_gradient(&i, vertex, method))
+            .collect()
+    }
+
     /// Calculates the natural neighbor weights corresponding to a given position.
     ///
     /// The weight of a natural neighbor n is defined as the size of the intersection of two areas:
@@ -512,59 +1307,645 @@ where
             // The positive and negative contributions are calculated separately to avoid precision issues.
             // The factor of 0.5 can be omitted as the weights are normalized anyway.
 
-            // `stop_edge` is used to know when to stop the inner loop (= once the polygon is finished)
-            let stop_edge = self.triangulation.directed_edge(*stop_edge);
-            assert!(!stop_edge.is_outer_edge());
+            // `stop_edge` is used to know when to stop the inner loop (= once the polygon is finished)
+            let stop_edge = self.triangulation.directed_edge(*stop_edge);
+            assert!(!stop_edge.is_outer_edge());
+
+            let mut positive_area = first.x * last.y;
+            let mut negative_area = first.y * last.x;
+
+            loop {
+                // All other polygon vertices happen lie on the circumcenter of a face adjacent to an
+                // out edge of the current natural neighbor.
+                //
+                // The natural_neighbor_polygon.svg refers to this variable as `c0`, `c1`, and `c2`.
+                let current = last_edge.face().as_inner().unwrap().circumcenter();
+                positive_area = positive_area + last.x * current.y;
+                negative_area = negative_area + last.y * current.x;
+
+                last_edge = last_edge.next().rev();
+                last = current;
+
+                if last_edge == stop_edge.rev() {
+                    positive_area = positive_area + current.x * first.y;
+                    negative_area = negative_area + current.y * first.x;
+                    break;
+                }
+            }
+
+            let polygon_area = positive_area - negative_area;
+
+            total_area = total_area + polygon_area;
+            result.push((stop_edge.from().fix(), polygon_area));
+
+            last = *first;
+            last_edge = stop_edge;
+        }
+
+        for tuple in result {
+            tuple.1 = tuple.1 / total_area;
+        }
+    }
+}
+
+/// Averages the area weighted normals of all faces adjacent to a vertex.
+///
+/// The (unnormalized) normal of a face is the cross product of two of its edges in 3D. Its z
+/// coordinate equals twice the face's area.
+fn estimate_gradient_from_faces<V, DE, UE, F, I>(
+    vertex: VertexHandle<V, DE, UE, F>,
+    i: &I,
+) -> [V::Scalar; 2]
+where
+    V: HasPosition,
+    V::Scalar: Float,
+    I: Fn(VertexHandle<V, DE, UE, F>) -> V::Scalar,
+{
+    let p0 = vertex.position();
+    let h0 = i(vertex);
+    let mut normal: [V::Scalar; 3] = [zero(); 3];
+    for edge in vertex.out_edges() {
+        if edge.face().is_outer() {
+            continue;
+        }
+
+        let [v1, v2] = [edge.to(), edge.prev().from()];
+        let e1 = v1.position().sub(p0);
+        let e2 = v2.position().sub(p0);
+        let d1 = i(v1) - h0;
+        let d2 = i(v2) - h0;
+
+        normal[0] = normal[0] + e1.y * d2 - d1 * e2.y;
+        normal[1] = normal[1] + d1 * e2.x - e1.x * d2;
+        normal[2] = normal[2] + e1.x * e2.y - e1.y * e2.x;
+    }
+
+    if normal[2] <= zero() {
+        return [zero(), zero()];
+    }
+
+    [-normal[0] / normal[2], -normal[1] / normal[2]]
+}
+
+/// Fits a plane through a vertex and its neighbors with a weighted least squares approximation.
+///
+/// Each neighbor is weighted by its inverse squared distance multiplied with `weight`.
+/// `weight` receives the neighbor's index within the vertex's out edges.
+fn estimate_gradient_least_squares<V, DE, UE, F, I, W>(
+    vertex: VertexHandle<V, DE, UE, F>,
+    i: &I,
+    weight: W,
+) -> [V::Scalar; 2]
+where
+    V: HasPosition,
+    V::Scalar: Float,
+    I: Fn(VertexHandle<V, DE, UE, F>) -> V::Scalar,
+    W: Fn(usize, VertexHandle<V, DE, UE, F>) -> V::Scalar,
+{
+    let position = vertex.position();
+    let h0 = i(vertex);
+
+    // Normal equations of the least squares problem: [[xx, xy], [xy, yy]] * gradient = [xh, yh]
+    let [mut xx, mut xy, mut yy, mut xh, mut yh] = [zero(); 5];
+    for (index, edge) in vertex.out_edges().enumerate() {
+        let neighbor = edge.to();
+        let diff = neighbor.position().sub(position);
+        let w = weight(index, neighbor) / diff.length2();
+        let dh = i(neighbor) - h0;
+
+        xx = xx + w * diff.x * diff.x;
+        xy = xy + w * diff.x * diff.y;
+        yy = yy + w * diff.y * diff.y;
+        xh = xh + w * diff.x * dh;
+        yh = yh + w * diff.y * dh;
+    }
+
+    let det = xx * yy - xy * xy;
+    // Relative threshold - detects neighbors that are (almost) collinear
+    let epsilon: V::Scalar = 1e-10f32.into();
+    if det <= epsilon * xx * yy {
+        return [zero(), zero()];
+    }
+
+    [(yy * xh - xy * yh) / det, (xx * yh - xy * xh) / det]
+}
+
+/// Calculates the natural neighbor coordinates of a vertex relative to all other vertices.
+///
+/// The coordinates are stored in `result` in the order of the vertex's out edges. The coordinate of
+/// a neighbor equals the area of the vertex's voronoi cell that would belong to the neighbor if
+/// the vertex was removed. Only the Delaunay neighbors of a vertex can have a non zero coordinate.
+/// The three polygon buffers are only used to avoid allocations.
+///
+/// Returns `false` if the vertex's voronoi cell is unbounded.
+fn get_vertex_sibson_coordinates<V, DE, UE, F>(
+    vertex: VertexHandle<V, DE, UE, F>,
+    [cell, region, clipped]: [&mut Vec<Point2<V::Scalar>>; 3],
+    result: &mut Vec<(FixedVertexHandle, V::Scalar)>,
+) -> bool
+where
+    V: HasPosition,
+    V::Scalar: Float,
+{
+    result.clear();
+
+    cell.clear();
+    for edge in vertex.out_edges() {
+        match edge.face().as_inner() {
+            Some(face) => cell.push(face.circumcenter()),
+            None => return false,
+        }
+    }
+
+    if cell.len() < 3 {
+        return false;
+    }
+
+    let mut total_area = zero();
+    for (index, edge) in vertex.out_edges().enumerate() {
+        let site = edge.to().position();
+        region.clear();
+        region.extend_from_slice(cell);
+        for (other_index, other) in vertex.out_edges().enumerate() {
+            if other_index == index || region.is_empty() {
+                continue;
+            }
+            math::clip_polygon_with_bisector(region, site, other.to().position(), clipped);
+            core::mem::swap(region, clipped);
+        }
+
+        let mut area: V::Scalar = zero();
+        for (region_index, from) in region.iter().enumerate() {
+            let to = region[(region_index + 1) % region.len()];
+            area = area + from.x * to.y - to.x * from.y;
+        }
+        let area = area.abs();
+
+        total_area = total_area + area;
+        result.push((edge.to().fix(), area));
+    }
+
+    if total_area <= zero() {
+        return false;
+    }
+
+    for tuple in result {
+        tuple.1 = tuple.1 / total_area;
+    }
+    true
+}
+
+impl<'a, V, DE, UE, F, L> InverseDistanceWeighting<'a, DelaunayTriangulation<V, DE, UE, F, L>>
+where
+    V: HasPosition,
+    DE: Default,
+    UE: Default,
+    F: Default,
+    L: HintGenerator<<V as HasPosition>::Scalar>,
+    <V as HasPosition>::Scalar: Float,
+{
+    pub(crate) fn new(triangulation: &'a DelaunayTriangulation<V, DE, UE, F, L>) -> Self {
+        Self {
+            triangulation,
+            power: 2.0f32.into(),
+            inspect_edges_buffer: Default::default(),
+            natural_neighbor_buffer: Default::default(),
+            weight_buffer: Default::default(),
+        }
+    }
+
+    /// Sets the power parameter.
+    ///
+    /// Larger values increase the influence of close vertices. A value of `0.0` weights all natural
+    /// neighbors equally. Defaults to `2.0`.
+    pub fn with_power(mut self, power: <V as HasPosition>::Scalar) -> Self {
+        self.power = power;
+        self
+    }
+
+    /// Calculates the inverse distance weights of a given query position.
+    ///
+    /// The weights are stored in the `result` parameter which will be cleared initially.
+    /// They will add up to 1.0.
+    ///
+    /// `result` contains the natural neighbors of the query position (see [NaturalNeighbor::get_weights]):
+    /// - `result` will be **empty** if the query position lies outside of the triangulation's convex hull
+    /// - `result` will contain **exactly one** vertex if the query position is equal to that vertex position.
+    /// - `result` will contain **at least two** `(vertex, weight)` tuples otherwise.
+    pub fn get_weights(
+        &self,
+        position: Point2<<V as HasPosition>::Scalar>,
+        result: &mut Vec<(FixedVertexHandle, <V as HasPosition>::Scalar)>,
+    ) {
+        result.clear();
+        let nns = &mut *self.natural_neighbor_buffer.borrow_mut();
+        get_natural_neighbor_edges(
+            self.triangulation,
+            &mut self.inspect_edges_buffer.borrow_mut(),
+            position,
+            &mut None,
+            nns,
+        );
+
+        let exponent = self.power / 2.0f32.into();
+        let mut total_weight = zero();
+        for edge in nns.iter() {
+            let vertex = self.triangulation.directed_edge(*edge).from();
+            let distance_2 = vertex.position().distance_2(position);
+            if distance_2 == zero() {
+                result.clear();
+                result.push((vertex.fix(), one()));
+                return;
+            }
+
+            let weight = one::<<V as HasPosition>::Scalar>() / distance_2.powf(exponent);
+            total_weight = total_weight + weight;
+            result.push((vertex.fix(), weight));
+        }
+
+        for tuple in result {
+            tuple.1 = tuple.1 / total_weight;
+        }
+    }
+
+    /// Interpolates a value at a given position.
+    ///
+    /// Returns `None` for any point outside the triangulation's convex hull.
+    /// The value to interpolate is given by the `i` parameter.
+    pub fn interpolate<I>(
+        &self,
+        i: I,
+        position: Point2<<V as HasPosition>::Scalar>,
+    ) -> Option<<V as HasPosition>::Scalar>
+    where
+        I: Fn(VertexHandle<V, DE, UE, F>) -> <V as HasPosition>::Scalar,
+    {
+        let nns = &mut *self.weight_buffer.borrow_mut();
+        self.get_weights(position, nns);
+        if nns.is_empty() {
+            return None;
+        }
+
+        let mut total_sum = zero();
+        for (vertex, weight) in nns {
+            total_sum = total_sum + i(self.triangulation.vertex(*vertex)) * *weight;
+        }
+        Some(total_sum)
+    }
+
+    /// Interpolates an arbitrary [Interpolatable] value at a given position.
+    ///
+    /// Returns `None` for any point outside the triangulation's convex hull.
+    pub fn interpolate_value<R, I>(
+        &self,
+        i: I,
+        position: Point2<<V as HasPosition>::Scalar>,
+    ) -> Option<R>
+    where
+        R: Interpolatable<<V as HasPosition>::Scalar>,
+        I: Fn(VertexHandle<V, DE, UE, F>) -> R,
+    {
+        let nns = &mut *self.weight_buffer.borrow_mut();
+        self.get_weights(position, nns);
+        weighted_sum(self.triangulation, nns, i)
+    }
+}
+
+impl<'a, T> CloughTocher<'a, T>
+where
+    T: Triangulation,
+    <T::Vertex as HasPosition>::Scalar: Float,
+{
+    pub(crate) fn new(triangulation: &'a T) -> Self {
+        Self { triangulation }
+    }
+
+    /// Interpolates a value at a given position.
+    ///
+    /// The value to interpolate is given by the `i` parameter. The gradient at each vertex is given by the
+    /// `g` parameter. The interpolation will match both the value and the gradient at every vertex.
+    ///
+    /// Returns `None` for any point outside of the triangulation's convex hull or if the triangulation
+    /// contains no inner face.
+    ///
+    /// # Example
+    /// ```
+    /// use spade::{DelaunayTriangulation, FloatTriangulation, GradientEstimation, Point2, Triangulation};
+    /// # fn main() -> Result<(), spade::InsertionError> {
+    /// let triangulation = DelaunayTriangulation::<Point2<f64>>::bulk_load(vec![
+    ///     Point2::new(0.0, 0.0),
+    ///     Point2::new(2.0, 0.0),
+    ///     Point2::new(0.0, 2.0),
+    ///     Point2::new(3.0, 3.0),
+    ///     Point2::new(1.0, 0.5),
+    /// ])?;
+    ///
+    /// // Interpolates the parabola x * x + y * y, using its exact gradient
+    /// let value = triangulation.clough_tocher().interpolate(
+    ///     |v| v.position().x.powi(2) + v.position().y.powi(2),
+    ///     |v| [2.0 * v.position().x, 2.0 * v.position().y],
+    ///     Point2::new(1.0, 1.5),
+    /// );
+    /// assert!((value.unwrap() - 3.25).abs() < 1e-10);
+    ///
+    /// // Estimates the gradients if they are not known
+    /// let height = |v: spade::handles::VertexHandle<Point2<f64>>| v.position().x.powi(2);
+    /// let gradients = triangulation
+    ///     .natural_neighbor()
+    ///     .estimate_gradients(height, GradientEstimation::Sibson);
+    /// let value = triangulation.clough_tocher().interpolate(
+    ///     height,
+    ///     |v| gradients[v.index()],
+    ///     Point2::new(1.0, 1.5),
+    /// );
+    /// assert!(value.is_some());
+    /// # Ok(()) }
+    /// ```
+    pub fn interpolate<I, G>(
+        &self,
+        i: I,
+        g: G,
+        position: Point2<<T::Vertex as HasPosition>::Scalar>,
+    ) -> Option<<T::Vertex as HasPosition>::Scalar>
+    where
+        I: Fn(
+            VertexHandle<T::Vertex, T::DirectedEdge, T::UndirectedEdge, T::Face>,
+        ) -> <T::Vertex as HasPosition>::Scalar,
+        G: Fn(
+            VertexHandle<T::Vertex, T::DirectedEdge, T::UndirectedEdge, T::Face>,
+        ) -> [<T::Vertex as HasPosition>::Scalar; 2],
+    {
+        let face = match self.triangulation.locate(position) {
+            PositionInTriangulation::OnVertex(vertex) => {
+                return Some(i(self.triangulation.vertex(vertex)))
+            }
+            PositionInTriangulation::OnEdge(edge) => {
+                let edge = self.triangulation.directed_edge(edge);
+                edge.face()
+                    .as_inner()
+                    .or_else(|| edge.rev().face().as_inner())?
+            }
+            PositionInTriangulation::OnFace(face) => self.triangulation.face(face),
+            _ => return None,
+        };
+
+        let vertices = face.vertices();
+        let positions = vertices.map(|vertex| vertex.position());
+        let values = vertices.map(&i);
+        let gradients = vertices.map(|vertex| Point2::from(g(vertex)));
+        Some(clough_tocher_patch(
+            positions,
+            values,
+            gradients,
+            face.barycentric_interpolation(position),
+        ))
+    }
+}
+
+/// Evaluates the Clough-Tocher macro element of a single triangle.
+///
+/// `coordinates` are the barycentric coordinates of the query position relative to `positions`.
+///
+/// Each sub triangle `[p_i, p_j, centroid]` carries a cubic bezier patch with control points `b_abc`
+/// (`a + b + c = 3`, `a` refers to `p_i`, `b` to `p_j` and `c` to the centroid). Control points on
+/// shared edges are identical for adjacent sub triangles.
+fn clough_tocher_patch<S>(
+    positions: [Point2<S>; 3],
+    values: [S; 3],
+    gradients: [Point2<S>; 3],
+    coordinates: [S; 3],
+) -> S
+where
+    S: SpadeNum + Float,
+{
+    let one = S::one();
+    let two = one + one;
+    let three = two + one;
+    let six = three + three;
+
+    let centroid = positions[0]
+        .add(positions[1])
+        .add(positions[2])
+        .mul(one / three);
+
+    // Control point next to vertex `from` on the edge towards `to`
+    let edge_point = |from: usize, to: Point2<S>| {
+        values[from] + to.sub(positions[from]).dot(gradients[from]) / three
+    };
+
+    // Control points next to each vertex on the line towards the centroid
+    let towards_centroid = [0, 1, 2].map(|index| edge_point(index, centroid));
+
+    // Inner control point of the sub triangle adjacent to edge [i, j]. It is chosen such that the
+    // derivative perpendicular to the edge is linear along the edge. This only depends on the values
+    // and gradients of i and j and is thus identical for both triangles sharing that edge.
+    let inner_point = |i: usize, j: usize| {
+        let b300 = values[i];
+        let b030 = values[j];
+        let b210 = edge_point(i, positions[j]);
+        let b120 = edge_point(j, positions[i]);
+        let b201 = towards_centroid[i];
+        let b021 = towards_centroid[j];
+
+        let edge = positions[j].sub(positions[i]);
+        let midpoint = positions[i].add(positions[j]).mul(one / two);
+        let tangential = centroid.sub(midpoint).dot(edge) / edge.length2();
+
+        // Bezier coefficients of the derivative towards the centroid and along the edge.
+        let q0 = b201 - (b300 + b210) / two;
+        let q2 = b021 - (b120 + b030) / two;
+        let second_difference = b030 - three * b120 + three * b210 - b300;
+
+        let q1 = (q0 + q2 - tangential * second_difference) / two;
+        q1 + (b210 + b120) / two
+    };
+
+    let inner = [inner_point(0, 1), inner_point(1, 2), inner_point(2, 0)];
+
+    // Control points on the lines from each vertex to the centroid, next to the centroid. These and the
+    // center point follow from the C1 condition between the sub triangles.
+    let near_centroid = [
+        (towards_centroid[0] + inner[0] + inner[2]) / three,
+        (towards_centroid[1] + inner[1] + inner[0]) / three,
+        (towards_centroid[2] + inner[2] + inner[1]) / three,
+    ];
+    let center = (near_centroid[0] + near_centroid[1] + near_centroid[2]) / three;
+
+    // The sub triangle opposite of the vertex with the smallest coordinate contains the query position.
+    let [l0, l1, l2] = coordinates;
+    let k = if l0 <= l1 && l0 <= l2 {
+        0
+    } else if l1 <= l2 {
+        1
+    } else {
+        2
+    };
+    let i = (k + 1) % 3;
+    let j = (k + 2) % 3;
+
+    let u = coordinates[i] - coordinates[k];
+    let v = coordinates[j] - coordinates[k];
+    let w = three * coordinates[k];
+
+    let b300 = values[i];
+    let b030 = values[j];
+    let b210 = edge_point(i, positions[j]);
+    let b120 = edge_point(j, positions[i]);
+    let b201 = towards_centroid[i];
+    let b021 = towards_centroid[j];
+    let b111 = inner[i];
+    let b102 = near_centroid[i];
+    let b012 = near_centroid[j];
+    let b003 = center;
+
+    b300 * u * u * u
+        + b030 * v * v * v
+        + b003 * w * w * w
+        + three * (b210 * u * u * v + b120 * u * v * v)
+        + three * (b201 * u * u * w + b021 * v * v * w)
+        + three * (b102 * u * w * w + b012 * v * w * w)
+        + six * b111 * u * v * w
+}
 
-            let mut positive_area = first.x * last.y;
-            let mut negative_area = first.y * last.x;
+/// Sums up the values of several vertices, weighted by the given weights.
+///
+/// Returns `None` if `weights` is empty.
+fn weighted_sum<T, R, I>(
+    triangulation: &T,
+    weights: &[(FixedVertexHandle, <T::Vertex as HasPosition>::Scalar)],
+    i: I,
+) -> Option<R>
+where
+    T: Triangulation,
+    R: Interpolatable<<T::Vertex as HasPosition>::Scalar>,
+    I: Fn(VertexHandle<T::Vertex, T::DirectedEdge, T::UndirectedEdge, T::Face>) -> R,
+{
+    if weights.is_empty() {
+        return None;
+    }
 
-            loop {
-                // All other polygon vertices happen lie on the circumcenter of a face adjacent to an
-                // out edge of the current natural neighbor.
-                //
-                // The natural_neighbor_polygon.svg refers to this variable as `c0`, `c1`, and `c2`.
-                let current = last_edge.face().as_inner().unwrap().circumcenter();
-                positive_area = positive_area + last.x * current.y;
-                negative_area = negative_area + last.y * current.x;
+    let mut result = R::zero();
+    for (vertex, weight) in weights {
+        result = result.add_weighted(i(triangulation.vertex(*vertex)), *weight);
+    }
+    Some(result)
+}
 
-                last_edge = last_edge.next().rev();
-                last = current;
+/// Calculates the weights of a position outside of the convex hull.
+///
+/// `location` must be the result of locating `position`.
+/// `result` is left empty if extrapolation is disabled or if the triangulation is empty.
+fn get_extrapolation_weights<T>(
+    triangulation: &T,
+    extrapolation: Extrapolation,
+    location: PositionInTriangulation,
+    position: Point2<<T::Vertex as HasPosition>::Scalar>,
+    result: &mut Vec<(FixedVertexHandle, <T::Vertex as HasPosition>::Scalar)>,
+) where
+    T: Triangulation,
+    <T::Vertex as HasPosition>::Scalar: Float,
+{
+    result.clear();
+    if extrapolation == Extrapolation::Disabled {
+        return;
+    }
 
-                if last_edge == stop_edge.rev() {
-                    positive_area = positive_area + current.x * first.y;
-                    negative_area = negative_area + current.y * first.x;
-                    break;
-                }
+    let edge = match location {
+        PositionInTriangulation::OutsideOfConvexHull(edge) => edge,
+        PositionInTriangulation::NoTriangulation => {
+            if let Some(vertex) = triangulation.vertices().next() {
+                result.push((vertex.fix(), one()));
             }
+            return;
+        }
+        _ => return,
+    };
 
-            let polygon_area = positive_area - negative_area;
-
-            total_area = total_area + polygon_area;
-            result.push((stop_edge.from().fix(), polygon_area));
+    let edge = nearest_hull_edge(triangulation, edge, position);
+    let [from, to] = edge.vertices();
+    let projection = math::project_point(from.position(), to.position(), position);
+    let rel = projection.relative_position().max(zero()).min(one());
 
-            last = *first;
-            last_edge = stop_edge;
+    match extrapolation {
+        Extrapolation::NearestHullFace => {
+            if let Some(face) = edge.rev().face().as_inner() {
+                let [v0, v1, v2] = face.vertices();
+                let [c0, c1, c2] = face.barycentric_interpolation(position);
+                result.extend([(v0.fix(), c0), (v1.fix(), c1), (v2.fix(), c2)]);
+                return;
+            }
         }
+        Extrapolation::NearestNeighbor => {
+            let start = if rel < 0.5.into() { from } else { to };
+            let nearest = triangulation.walk_to_nearest_neighbor(start.fix(), position);
+            result.push((nearest.fix(), one()));
+            return;
+        }
+        Extrapolation::Disabled | Extrapolation::NearestHullEdge => {}
+    }
 
-        for tuple in result {
-            tuple.1 = tuple.1 / total_area;
+    // Degenerate triangulations have no inner face. They fall back to the nearest hull edge.
+    result.push((
+        from.fix(),
+        one::<<T::Vertex as HasPosition>::Scalar>() - rel,
+    ));
+    result.push((to.fix(), rel));
+}
+
+/// Returns the convex hull edge that is closest to a position outside of the convex hull.
+///
+/// `start` must be a convex hull edge with the outer face and `position` on its left side. The search
+/// walks along the convex hull as long as the distance decreases.
+fn nearest_hull_edge<'a, T>(
+    triangulation: &'a T,
+    start: FixedDirectedEdgeHandle,
+    position: Point2<<T::Vertex as HasPosition>::Scalar>,
+) -> DirectedEdgeHandle<'a, T::Vertex, T::DirectedEdge, T::UndirectedEdge, T::Face>
+where
+    T: Triangulation,
+    <T::Vertex as HasPosition>::Scalar: Float,
+{
+    let distance = |edge: DirectedEdgeHandle<_, _, _, _>| {
+        let [from, to] = edge.positions();
+        math::distance_2(from, to, position)
+    };
+
+    let mut nearest = triangulation.directed_edge(start);
+    let mut nearest_distance = distance(nearest);
+    for forward in [true, false] {
+        loop {
+            let candidate = if forward {
+                nearest.next()
+            } else {
+                nearest.prev()
+            };
+            let candidate_distance = distance(candidate);
+            if candidate_distance >= nearest_distance {
+                break;
+            }
+            nearest = candidate;
+            nearest_distance = candidate_distance;
         }
     }
+    nearest
 }
 
+/// Returns the location of `position`.
 fn get_natural_neighbor_edges<T>(
     triangulation: &T,
     inspect_buffer: &mut Vec<FixedDirectedEdgeHandle>,
     position: Point2<<T::Vertex as HasPosition>::Scalar>,
+    hint: &mut Option<FixedVertexHandle>,
     result: &mut Vec<FixedDirectedEdgeHandle>,
-) where
+) -> PositionInTriangulation
+where
     T: Triangulation,
     <T::Vertex as HasPosition>::Scalar: Float,
 {
     inspect_buffer.clear();
     result.clear();
-    match triangulation.locate(position) {
+    let location = locate_and_update_hint(triangulation, position, hint);
+    match location {
         PositionInTriangulation::OnFace(face) => {
             for edge in triangulation
                 .face(face)
@@ -581,7 +1962,7 @@ fn get_natural_neighbor_edges<T>(
 
             if edge.is_part_of_convex_hull() {
                 result.extend([edge.fix(), edge.fix().rev()]);
-                return;
+                return location;
             }
 
             for edge in [edge, edge.rev()] {
@@ -600,6 +1981,63 @@ fn get_natural_neighbor_edges<T>(
         _ => {}
     }
     result.reverse();
+    location
+}
+
+/// Locates a position, starting at `hint` if it is set.
+///
+/// `hint` is updated to a vertex close to the position. Subsequent calls with close positions will
+/// then run in `O(1)` on average.
+fn locate_and_update_hint<T>(
+    triangulation: &T,
+    position: Point2<<T::Vertex as HasPosition>::Scalar>,
+    hint: &mut Option<FixedVertexHandle>,
+) -> PositionInTriangulation
+where
+    T: Triangulation,
+{
+    let location = triangulation.locate_with_hint_option_core(position, *hint);
+    match location {
+        PositionInTriangulation::OnVertex(vertex) => *hint = Some(vertex),
+        PositionInTriangulation::OnEdge(edge)
+        | PositionInTriangulation::OutsideOfConvexHull(edge) => {
+            *hint = Some(triangulation.directed_edge(edge).from().fix())
+        }
+        PositionInTriangulation::OnFace(face) => {
+            *hint = Some(triangulation.face(face).vertices()[0].fix())
+        }
+        PositionInTriangulation::NoTriangulation => {}
+    }
+    location
+}
+
+/// Interpolates the grid positions of all rows stored within `result`, starting at row `first_row`.
+///
+/// Every row starts its lookup at the first position of the previous row.
+fn interpolate_grid_rows<S, R, I>(
+    grid: &InterpolationGrid<S>,
+    first_row: usize,
+    result: &mut [Option<R>],
+    mut interpolate: I,
+) where
+    S: SpadeNum,
+    I: FnMut(Point2<S>, &mut Option<FixedVertexHandle>) -> Option<R>,
+{
+    if grid.columns == 0 {
+        return;
+    }
+
+    let mut row_hint = None;
+    for (row_offset, row_result) in result.chunks_mut(grid.columns).enumerate() {
+        let row = first_row + row_offset;
+        let mut hint = row_hint;
+        for (column, value) in row_result.iter_mut().enumerate() {
+            *value = interpolate(grid.position(column, row), &mut hint);
+            if column == 0 {
+                row_hint = hint;
+            }
+        }
+    }
 }
 
 /// Identifies natural neighbors.
@@ -671,7 +2109,10 @@ mod test {
     use approx::assert_ulps_eq;
 
     use crate::test_utilities::{random_points_in_range, random_points_with_seed, SEED, SEED2};
-    use crate::{DelaunayTriangulation, HasPosition, InsertionError, Point2, Triangulation};
+    use crate::{
+        DelaunayTriangulation, Extrapolation, FloatTriangulation, GradientEstimation, HasPosition,
+        InsertionError, InterpolationGrid, Point2, Triangulation,
+    };
     use alloc::vec;
     use alloc::vec::Vec;
 
@@ -703,7 +2144,7 @@ mod test {
 
         let mut buffer = Vec::new();
         let query_point = Point2::new(0.5, 0.2);
-        super::get_natural_neighbor_edges(&t, &mut buffer, query_point, &mut nn_edges);
+        super::get_natural_neighbor_edges(&t, &mut buffer, query_point, &mut None, &mut nn_edges);
 
         assert!(nn_edges.len() >= 3);
 
@@ -892,4 +2333,506 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_interpolate_gradient_linear() -> Result<(), InsertionError> {
+        let mut t = DelaunayTriangulation::<_>::new();
+        for v in random_points_with_seed(100, SEED) {
+            t.insert(PointWithHeight::new(v, 2.0 * v.x - 3.0 * v.y + 1.0))?;
+        }
+
+        // A linear function must be reproduced exactly if its gradient is given
+        let nn = t.natural_neighbor();
+        for point in random_points_in_range(0.5, 50, SEED2) {
+            let value = nn
+                .interpolate_gradient(|v| v.data().height, |_| [2.0, -3.0], 1.0, point)
+                .unwrap();
+            assert_ulps_eq!(value, 2.0 * point.x - 3.0 * point.y + 1.0, epsilon = 1e-10);
+        }
+
+        Ok(())
+    }
+
+    const GRADIENT_ESTIMATIONS: [GradientEstimation; 3] = [
+        GradientEstimation::AreaWeightedFaceNormals,
+        GradientEstimation::LeastSquares,
+        GradientEstimation::Sibson,
+    ];
+
+    #[test]
+    fn test_estimate_gradients_linear() -> Result<(), InsertionError> {
+        let mut t = DelaunayTriangulation::<_>::new();
+        for v in random_points_with_seed(100, SEED) {
+            t.insert(PointWithHeight::new(v, 2.0 * v.x - 3.0 * v.y + 1.0))?;
+        }
+
+        let nn = t.natural_neighbor();
+        for method in GRADIENT_ESTIMATIONS {
+            let gradients = nn.estimate_gradients(|v| v.data().height, method);
+            assert_eq!(gradients.len(), t.num_vertices());
+            for [gx, gy] in &gradients {
+                assert_ulps_eq!(*gx, 2.0, epsilon = 1e-8);
+                assert_ulps_eq!(*gy, -3.0, epsilon = 1e-8);
+            }
+
+            for point in random_points_in_range(0.5, 20, SEED2) {
+                let value = nn
+                    .interpolate_gradient(|v| v.data().height, |v| gradients[v.index()], 1.0, point)
+                    .unwrap();
+                assert_ulps_eq!(value, 2.0 * point.x - 3.0 * point.y + 1.0, epsilon = 1e-8);
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_estimate_gradients_parabola() -> Result<(), InsertionError> {
+        let mut t = DelaunayTriangulation::<_>::new();
+        let grid_size = 16;
+        let scale = 1.0 / grid_size as f64;
+        for x in -grid_size..=grid_size {
+            for y in -grid_size..=grid_size {
+                let coords = Point2::new(x as f64, y as f64).mul(scale);
+                t.insert(PointWithHeight::new(coords, coords.length2()))?;
+            }
+        }
+
+        let nn = t.natural_neighbor();
+        for method in GRADIENT_ESTIMATIONS {
+            for vertex in t.vertices() {
+                if vertex.out_edges().any(|edge| edge.is_outer_edge()) {
+                    continue;
+                }
+
+                let position = vertex.position();
+                let [gx, gy] = nn.estimate_gradient(|v| v.data().height, vertex.fix(), method);
+                assert_ulps_eq!(gx, 2.0 * position.x, epsilon = 0.1);
+                assert_ulps_eq!(gy, 2.0 * position.y, epsilon = 0.1);
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_estimate_gradients_degenerate() -> Result<(), InsertionError> {
+        let mut t = DelaunayTriangulation::<_>::new();
+        let v0 = t.insert(PointWithHeight::new(Point2::new(0.0, 0.0), 1.0))?;
+        let nn = t.natural_neighbor();
+        for method in GRADIENT_ESTIMATIONS {
+            assert_eq!(
+                nn.estimate_gradient(|v| v.data().height, v0, method),
+                [0.0, 0.0]
+            );
+        }
+
+        // All vertices are collinear
+        t.insert(PointWithHeight::new(Point2::new(1.0, 1.0), 2.0))?;
+        t.insert(PointWithHeight::new(Point2::new(2.0, 2.0), 3.0))?;
+        let nn = t.natural_neighbor();
+        for method in GRADIENT_ESTIMATIONS {
+            assert_eq!(
+                nn.estimate_gradient(|v| v.data().height, v0, method),
+                [0.0, 0.0]
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_extrapolation_nearest_hull_edge() -> Result<(), InsertionError> {
+        let t = DelaunayTriangulation::<_>::bulk_load(random_points_with_seed(100, SEED))?;
+        let barycentric = t
+            .barycentric()
+            .with_extrapolation(Extrapolation::NearestHullEdge);
+
+        let mut weights = Vec::new();
+        for position in random_points_in_range(3.0, 200, SEED2) {
+            barycentric.get_weights(position, &mut weights);
+            assert!(!weights.is_empty());
+
+            let mut projected = Point2::new(0.0, 0.0);
+            for (vertex, weight) in &weights {
+                assert!(*weight >= 0.0);
+                projected = projected.add(t.vertex(*vertex).position().mul(*weight));
+            }
+
+            if weights.len() == 2 {
+                let expected_distance = t
+                    .convex_hull()
+                    .map(|edge| {
+                        let [from, to] = edge.positions();
+                        crate::delaunay_core::math::distance_2(from, to, position)
+                    })
+                    .fold(f64::INFINITY, f64::min);
+                assert_ulps_eq!(
+                    projected.distance_2(position),
+                    expected_distance,
+                    epsilon = 1e-10
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_extrapolation_nearest_hull_face() -> Result<(), InsertionError> {
+        let mut t = DelaunayTriangulation::<_>::new();
+        for v in random_points_with_seed(50, SEED) {
+            t.insert(PointWithHeight::new(v, 2.0 * v.x - 3.0 * v.y + 1.0))?;
+        }
+
+        let nn = t
+            .natural_neighbor()
+            .with_extrapolation(Extrapolation::NearestHullFace);
+        let barycentric = t
+            .barycentric()
+            .with_extrapolation(Extrapolation::NearestHullFace);
+
+        for position in random_points_in_range(1.5, 100, SEED2) {
+            let expected = 2.0 * position.x - 3.0 * position.y + 1.0;
+            let value = nn.interpolate(|v| v.data().height, position).unwrap();
+            assert_ulps_eq!(value, expected, epsilon = 1e-8);
+            let value = barycentric
+                .interpolate(|v| v.data().height, position)
+                .unwrap();
+            assert_ulps_eq!(value, expected, epsilon = 1e-8);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_extrapolation_nearest_neighbor() -> Result<(), InsertionError> {
+        let t = DelaunayTriangulation::<_>::bulk_load(random_points_with_seed(100, SEED))?;
+        let nn = t
+            .natural_neighbor()
+            .with_extrapolation(Extrapolation::NearestNeighbor);
+
+        let mut weights = Vec::new();
+        for position in random_points_in_range(3.0, 200, SEED2) {
+            nn.get_weights(position, &mut weights);
+            let nearest = t.nearest_neighbor(position).unwrap();
+            if weights.len() == 1 {
+                let vertex = t.vertex(weights[0].0);
+                assert_eq!(
+                    vertex.position().distance_2(position),
+                    nearest.position().distance_2(position)
+                );
+            } else {
+                assert!(weights.len() >= 3);
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_extrapolation_degenerate() -> Result<(), InsertionError> {
+        let mut t = DelaunayTriangulation::<_>::new();
+        let outside = Point2::new(1.0, 3.0);
+        let barycentric = t
+            .barycentric()
+            .with_extrapolation(Extrapolation::NearestNeighbor);
+        assert_eq!(barycentric.interpolate(|_| 1.0, outside), None);
+
+        t.insert(PointWithHeight::new(Point2::new(0.0, 0.0), 1.0))?;
+        for extrapolation in [
+            Extrapolation::NearestHullEdge,
+            Extrapolation::NearestHullFace,
+            Extrapolation::NearestNeighbor,
+        ] {
+            let nn = t.natural_neighbor().with_extrapolation(extrapolation);
+            assert_eq!(nn.interpolate(|v| v.data().height, outside), Some(1.0));
+        }
+
+        // All vertices are collinear - no face can be extended
+        t.insert(PointWithHeight::new(Point2::new(2.0, 0.0), 3.0))?;
+        t.insert(PointWithHeight::new(Point2::new(4.0, 0.0), 5.0))?;
+        for extrapolation in [
+            Extrapolation::NearestHullEdge,
+            Extrapolation::NearestHullFace,
+        ] {
+            let barycentric = t.barycentric().with_extrapolation(extrapolation);
+            let value = barycentric.interpolate(|v| v.data().height, outside);
+            assert_eq!(value, Some(2.0));
+        }
+
+        let nn = t.natural_neighbor();
+        assert_eq!(nn.interpolate(|v| v.data().height, outside), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_interpolate_grid() -> Result<(), InsertionError> {
+        let mut t = DelaunayTriangulation::<_>::new();
+        for v in random_points_with_seed(100, SEED) {
+            t.insert(PointWithHeight::new(v, v.x * v.y))?;
+        }
+
+        let grid = InterpolationGrid::new(Point2::new(-1.2, -1.1), Point2::new(0.1, 0.05), 25, 45);
+        let positions = (0..grid.rows)
+            .flat_map(|row| (0..grid.columns).map(move |column| grid.position(column, row)))
+            .collect::<Vec<_>>();
+        assert_eq!(positions.len(), grid.num_positions());
+
+        for extrapolation in [Extrapolation::Disabled, Extrapolation::NearestHullEdge] {
+            let nn = t.natural_neighbor().with_extrapolation(extrapolation);
+            let barycentric = t.barycentric().with_extrapolation(extrapolation);
+
+            let expected_nn = positions
+                .iter()
+                .map(|position| nn.interpolate(|v| v.data().height, *position))
+                .collect::<Vec<_>>();
+            let expected_barycentric = positions
+                .iter()
+                .map(|position| barycentric.interpolate(|v| v.data().height, *position))
+                .collect::<Vec<_>>();
+
+            let mut result = vec![Some(1.0)];
+            nn.interpolate_grid(|v| v.data().height, &grid, &mut result);
+            assert_eq!(result, expected_nn);
+            nn.interpolate_points(|v| v.data().height, &positions, &mut result);
+            assert_eq!(result, expected_nn);
+
+            barycentric.interpolate_grid(|v| v.data().height, &grid, &mut result);
+            assert_eq!(result, expected_barycentric);
+            barycentric.interpolate_points(|v| v.data().height, &positions, &mut result);
+            assert_eq!(result, expected_barycentric);
+
+            #[cfg(feature = "rayon")]
+            {
+                nn.interpolate_grid_parallel(|v| v.data().height, &grid, &mut result);
+                assert_eq!(result, expected_nn);
+                barycentric.interpolate_grid_parallel(|v| v.data().height, &grid, &mut result);
+                assert_eq!(result, expected_barycentric);
+            }
+        }
+
+        // Multi component values
+        let nn = t.natural_neighbor();
+        let barycentric = t.barycentric();
+        let expected_nn = positions
+            .iter()
+            .map(|position| nn.interpolate_value(|v| [v.data().height, 1.0], *position))
+            .collect::<Vec<_>>();
+        let expected_barycentric = positions
+            .iter()
+            .map(|position| barycentric.interpolate_value(|v| [v.data().height, 1.0], *position))
+            .collect::<Vec<_>>();
+
+        let mut result = Vec::new();
+        nn.interpolate_grid(|v| [v.data().height, 1.0], &grid, &mut result);
+        assert_eq!(result, expected_nn);
+        nn.interpolate_points(|v| [v.data().height, 1.0], &positions, &mut result);
+        assert_eq!(result, expected_nn);
+        barycentric.interpolate_grid(|v| [v.data().height, 1.0], &grid, &mut result);
+        assert_eq!(result, expected_barycentric);
+        barycentric.interpolate_points(|v| [v.data().height, 1.0], &positions, &mut result);
+        assert_eq!(result, expected_barycentric);
+
+        #[cfg(feature = "rayon")]
+        {
+            nn.interpolate_grid_parallel(|v| [v.data().height, 1.0], &grid, &mut result);
+            assert_eq!(result, expected_nn);
+            barycentric.interpolate_grid_parallel(|v| [v.data().height, 1.0], &grid, &mut result);
+            assert_eq!(result, expected_barycentric);
+        }
+
+        let empty_grid = InterpolationGrid::new(Point2::new(0.0, 0.0), Point2::new(1.0, 1.0), 0, 5);
+        let mut result = vec![Some(1.0)];
+        t.natural_neighbor()
+            .interpolate_grid(|v| v.data().height, &empty_grid, &mut result);
+        assert!(result.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_interpolate_value() -> Result<(), InsertionError> {
+        let mut t = DelaunayTriangulation::<_>::new();
+        for v in random_points_with_seed(50, SEED) {
+            t.insert(PointWithHeight::new(v, v.x * v.y))?;
+        }
+
+        let channels = |v: &PointWithHeight| [v.height, v.position.x, 2.0 * v.position.y];
+        let nn = t
+            .natural_neighbor()
+            .with_extrapolation(Extrapolation::NearestHullEdge);
+        let barycentric = t.barycentric();
+
+        for position in random_points_in_range(1.2, 50, SEED2) {
+            let value = nn.interpolate_value(|v| channels(v.data()), position);
+            let expected = [0, 1, 2].map(|channel| {
+                nn.interpolate(|v| channels(v.data())[channel], position)
+                    .unwrap()
+            });
+            let value = value.unwrap();
+            for channel in 0..3 {
+                assert_ulps_eq!(value[channel], expected[channel]);
+            }
+
+            let value = barycentric.interpolate_value(|v| v.position(), position);
+            let expected = barycentric
+                .interpolate(|v| v.position().x, position)
+                .zip(barycentric.interpolate(|v| v.position().y, position))
+                .map(|(x, y)| Point2::new(x, y));
+            assert_eq!(value, expected);
+        }
+
+        let empty = DelaunayTriangulation::<Point2<f64>>::new();
+        assert_eq!(
+            empty
+                .barycentric()
+                .interpolate_value(|_| [1.0; 4], Point2::new(0.0, 0.0)),
+            None
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_inverse_distance_weighting() -> Result<(), InsertionError> {
+        let mut t = DelaunayTriangulation::<_>::new();
+        for v in random_points_with_seed(50, SEED) {
+            t.insert(PointWithHeight::new(v, v.x - v.y))?;
+        }
+
+        let idw = t.inverse_distance_weighting();
+        for vertex in t.vertices() {
+            let value = idw.interpolate(|v| v.data().height, vertex.position());
+            assert_eq!(value, Some(vertex.data().height));
+        }
+
+        let nn = t.natural_neighbor();
+        let mut weights = Vec::new();
+        let mut nn_weights = Vec::new();
+        for position in random_points_in_range(1.5, 100, SEED2) {
+            idw.get_weights(position, &mut weights);
+            nn.get_weights(position, &mut nn_weights);
+            assert_eq!(weights.len(), nn_weights.len());
+            if weights.is_empty() {
+                assert_eq!(idw.interpolate(|v| v.data().height, position), None);
+                continue;
+            }
+
+            assert_ulps_eq!(weights.iter().map(|(_, w)| *w).sum::<f64>(), 1.0);
+
+            // The closest natural neighbor has the largest weight
+            let (closest, _) = weights
+                .iter()
+                .min_by(|(v0, _), (v1, _)| {
+                    let d0 = t.vertex(*v0).position().distance_2(position);
+                    let d1 = t.vertex(*v1).position().distance_2(position);
+                    d0.partial_cmp(&d1).unwrap()
+                })
+                .unwrap();
+            let (heaviest, _) = weights
+                .iter()
+                .max_by(|(_, w0), (_, w1)| w0.partial_cmp(w1).unwrap())
+                .unwrap();
+            assert_eq!(closest, heaviest);
+
+            let value = idw.interpolate_value(|v| [v.data().height, 1.0], position);
+            assert_ulps_eq!(value.unwrap()[1], 1.0);
+        }
+
+        // A power of zero weights all natural neighbors equally
+        let idw = t.inverse_distance_weighting().with_power(0.0);
+        idw.get_weights(Point2::new(0.1, 0.2), &mut weights);
+        for (_, weight) in &weights {
+            assert_ulps_eq!(*weight, 1.0 / weights.len() as f64);
+        }
+
+        Ok(())
+    }
+
+    fn quadratic(position: Point2<f64>) -> (f64, [f64; 2]) {
+        let Point2 { x, y } = position;
+        let value = x * x + 3.0 * x * y - 2.0 * y * y + x - 1.0;
+        (value, [2.0 * x + 3.0 * y + 1.0, 3.0 * x - 4.0 * y])
+    }
+
+    #[test]
+    fn test_clough_tocher_quadratic_precision() -> Result<(), InsertionError> {
+        let t = DelaunayTriangulation::<_>::bulk_load(random_points_with_seed(50, SEED))?;
+        let clough_tocher = t.clough_tocher();
+
+        let mut num_inside = 0;
+        for position in random_points_in_range(1.5, 200, SEED2) {
+            let value = clough_tocher.interpolate(
+                |v| quadratic(v.position()).0,
+                |v| quadratic(v.position()).1,
+                position,
+            );
+
+            if let Some(value) = value {
+                assert_ulps_eq!(value, quadratic(position).0, epsilon = 1e-9);
+                num_inside += 1;
+            }
+        }
+        assert!(num_inside > 50);
+
+        for vertex in t.vertices() {
+            let value = clough_tocher.interpolate(|_| 2.0, |_| [1.0, 0.0], vertex.position());
+            assert_eq!(value, Some(2.0));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_clough_tocher_continuity() -> Result<(), InsertionError> {
+        let t = DelaunayTriangulation::<_>::bulk_load(random_points_with_seed(30, SEED))?;
+        let clough_tocher = t.clough_tocher();
+        let f = |position: Point2<f64>| {
+            clough_tocher
+                .interpolate(
+                    |v| v.position().x.sin() * (2.0 * v.position().y).cos(),
+                    |v| {
+                        let Point2 { x, y } = v.position();
+                        [x.cos() * (2.0 * y).cos(), -2.0 * x.sin() * (2.0 * y).sin()]
+                    },
+                    position,
+                )
+                .unwrap()
+        };
+
+        let h = 1e-5;
+        let check = |point: Point2<f64>, direction: Point2<f64>| {
+            let direction = direction.mul(1.0 / direction.length2().sqrt());
+            let before = point.sub(direction.mul(h));
+            let after = point.add(direction.mul(h));
+            let value = f(point);
+
+            assert_ulps_eq!(f(before), value, epsilon = 1e-4);
+            let derivative_before = (value - f(before)) / h;
+            let derivative_after = (f(after) - value) / h;
+            assert_ulps_eq!(derivative_before, derivative_after, epsilon = 1e-3);
+        };
+
+        for edge in t.undirected_edges() {
+            let edge = edge.as_directed();
+            if edge.is_part_of_convex_hull() {
+                continue;
+            }
+
+            // Across an edge of the triangulation
+            let [from, to] = edge.positions();
+            let midpoint = from.add(to.sub(from).mul(0.3));
+            let dir = to.sub(from);
+            check(midpoint, Point2::new(-dir.y, dir.x));
+
+            // Across a line between a vertex and the centroid of a face
+            let face = edge.face().as_inner().unwrap();
+            let [p0, p1, p2] = face.positions();
+            let centroid = p0.add(p1).add(p2).mul(1.0 / 3.0);
+            let point = from.add(centroid.sub(from).mul(0.6));
+            let dir = centroid.sub(from);
+            check(point, Point2::new(-dir.y, dir.x));
+        }
+
+        Ok(())
+    }
 }
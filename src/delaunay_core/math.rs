@@ -1,6 +1,8 @@
 use crate::{HasPosition, LineSideInfo, Point2, SpadeNum};
 use num_traits::{zero, Float};
 
+use alloc::vec::Vec;
+
 /// Indicates a point's projected position relative to an edge.
 ///
 /// This struct is usually the result of calling
@@ -288,6 +290,129 @@ where
     robust::incircle(v3, v2, v1, p) < 0.0
 }
 
+/// Checks if an edge can be flipped without creating overlapping faces.
+///
+/// The edge goes from `from` to `to`, `left` and `right` refer to the opposite vertices of its
+/// two adjacent faces. Flipping is possible if the quadrilateral formed by these vertices is
+/// strictly convex.
+pub fn is_edge_flippable<S>(
+    from: Point2<S>,
+    to: Point2<S>,
+    left: Point2<S>,
+    right: Point2<S>,
+) -> bool
+where
+    S: SpadeNum,
+{
+    side_query(right, left, from).is_on_left_side()
+        && side_query(right, left, to).is_on_right_side()
+}
+
+fn to_lifted_coord<S: SpadeNum>((position, weight): (Point2<S>, S)) -> robust::Coord3D<f64> {
+    let x: f64 = position.x.into();
+    let y: f64 = position.y.into();
+    robust::Coord3D {
+        x,
+        y,
+        z: x * x + y * y - weight.into(),
+    }
+}
+
+/// Tests the position of a weighted point relative to the power circle (orthogonal circle) of a
+/// triangle of weighted points.
+///
+/// The vertices `v1`, `v2` and `v3` are given by their position and weight and must be ordered
+/// counterclockwise. Returns a positive value if `p` lies inside the power circle, a negative
+/// value if it lies outside and zero if it lies on the power circle.
+///
+/// All points are lifted onto the paraboloid `z = x² + y² - weight`. The lifted coordinates
+/// are rounded to `f64`, the remaining test is exact. This keeps the results of all tests
+/// consistent with each other.
+pub fn power_circle_test<S>(
+    v1: (Point2<S>, S),
+    v2: (Point2<S>, S),
+    v3: (Point2<S>, S),
+    p: (Point2<S>, S),
+) -> f64
+where
+    S: SpadeNum,
+{
+    robust::orient3d(
+        to_lifted_coord(v1),
+        to_lifted_coord(v2),
+        to_lifted_coord(v3),
+        to_lifted_coord(p),
+    )
+}
+
+/// Returns `true` if a weighted point lies inside the power circle of a triangle.
+///
+/// See [power_circle_test].
+pub fn contained_in_power_circle<S>(
+    v1: (Point2<S>, S),
+    v2: (Point2<S>, S),
+    v3: (Point2<S>, S),
+    p: (Point2<S>, S),
+) -> bool
+where
+    S: SpadeNum,
+{
+    power_circle_test(v1, v2, v3, p) > 0.0
+}
+
+/// Tests the position of a weighted point that lies on the line through two weighted points.
+///
+/// This is the one dimensional equivalent of [power_circle_test]. If `p` lies between `from` and
+/// `to`, a positive value indicates that `p` would be part of the regular triangulation of all
+/// three points and a negative value indicates that `p` is hidden by the segment's end points.
+pub fn power_segment_test<S>(from: (Point2<S>, S), to: (Point2<S>, S), p: (Point2<S>, S)) -> f64
+where
+    S: SpadeNum,
+{
+    let [from, to, p] = [from, to, p].map(to_lifted_coord);
+
+    // The points are collinear. The test is performed in the vertical plane through the
+    // segment, using the coordinate axis that is closer to the segment's direction.
+    let project = |coord: robust::Coord3D<f64>, use_x: bool| robust::Coord {
+        x: if use_x { coord.x } else { coord.y },
+        y: coord.z,
+    };
+    let use_x = (to.x - from.x).abs() >= (to.y - from.y).abs();
+    let direction = if use_x { to.x - from.x } else { to.y - from.y };
+
+    let side = robust::orient2d(project(from, use_x), project(to, use_x), project(p, use_x));
+    if direction > 0.0 {
+        -side
+    } else {
+        side
+    }
+}
+
+/// Returns the power center of a triangle of weighted points.
+///
+/// The power center has the same power distance to all three vertices. It is equal to the
+/// circumcenter if all weights are equal.
+pub fn power_center<S>(positions: [Point2<S>; 3], weights: [S; 3]) -> Point2<S>
+where
+    S: SpadeNum + Float,
+{
+    let [v0, v1, v2] = positions;
+    let [w0, w1, w2] = weights;
+    let b = v1.sub(v0);
+    let c = v2.sub(v0);
+
+    let one = S::one();
+    let two = one + one;
+    let d = two * (b.x * c.y - c.x * b.y);
+    let len_b = b.dot(b) - w1 + w0;
+    let len_c = c.dot(c) - w2 + w0;
+    let d_inv: S = one / d;
+
+    let x = (len_b * c.y - len_c * b.y) * d_inv;
+    let y = (-len_b * c.x + len_c * b.x) * d_inv;
+    Point2::new(x, y).add(v0)
+}
+
 pub fn is_ordered_ccw<S>(p1: Point2<S>, p2: Point2<S>, query_point: Point2<S>) -> bool
 where
     S: SpadeNum,
@@ -341,6 +466,120 @@ where
     other_from != other_to && self_from != self_to
 }
 
+/// Returns the intersection point of the line through `from0` and `to0` and the edge from `from1` to `to1`.
+///
+/// The result is clamped to the second edge. The calculation is inaccurate and the result may not lie
+/// exactly on either line.
+pub fn line_intersection<S>(
+    from0: Point2<S>,
+    to0: Point2<S>,
+    from1: Point2<S>,
+    to1: Point2<S>,
+) -> Point2<S>
+where
+    S: SpadeNum + Float,
+{
+    let cross = |a: Point2<S>, b: Point2<S>| a.x * b.y - a.y * b.x;
+
+    let line_dir = to0.sub(from0);
+    let edge_dir = to1.sub(from1);
+
+    let denominator = cross(edge_dir, line_dir);
+    if denominator == S::zero() {
+        return from1;
+    }
+
+    let t = cross(from0.sub(from1), line_dir) / denominator;
+    let t = t.max(S::zero()).min(S::one());
+    from1.add(edge_dir.mul(t))
+}
+
+/// Clips a convex polygon with the perpendicular bisector of two points.
+///
+/// Only the part of the polygon that lies closer to `site` than to `other` is kept. The part is
+/// stored in `result` which is cleared first. It is empty if no such part exists. The calculation
+/// is inaccurate.
+pub fn clip_polygon_with_bisector<S>(
+    polygon: &[Point2<S>],
+    site: Point2<S>,
+    other: Point2<S>,
+    result: &mut Vec<Point2<S>>,
+) where
+    S: SpadeNum + Float,
+{
+    clip_polygon_with_power_bisector(polygon, (site, S::zero()), (other, S::zero()), result)
+}
+
+/// Clips a convex polygon with the power bisector of two weighted points.
+///
+/// Only the part of the polygon that has a smaller power distance to `site` than to `other` is
+/// kept. The power distance of a position `p` to a weighted point `(v, weight)` is
+/// `|p - v|² - weight`. The bisector is perpendicular to the line through both points but is
+/// moved towards the point with the smaller weight. See [clip_polygon_with_bisector] for more
+/// information.
+pub fn clip_polygon_with_power_bisector<S>(
+    polygon: &[Point2<S>],
+    (site, site_weight): (Point2<S>, S),
+    (other, other_weight): (Point2<S>, S),
+    result: &mut Vec<Point2<S>>,
+) where
+    S: SpadeNum + Float,
+{
+    let two = S::one() + S::one();
+    let normal = other.sub(site);
+    let shift = (site_weight - other_weight) / (two * normal.length2());
+    let midpoint = site.add(other).mul(S::one() / two).add(normal.mul(shift));
+    // Positive for points closer to other
+    let distance = |point: Point2<S>| point.sub(midpoint).dot(normal);
+
+    result.clear();
+    for (index, from) in polygon.iter().enumerate() {
+        let to = polygon[(index + 1) % polygon.len()];
+        let from_distance = distance(*from);
+        let to_distance = distance(to);
+
+        if from_distance <= S::zero() {
+            result.push(*from);
+        }
+
+        if (from_distance < S::zero()) != (to_distance < S::zero())
+            && from_distance != S::zero()
+            && to_distance != S::zero()
+        {
+            let t = from_distance / (from_distance - to_distance);
+            result.push(from.add(to.sub(*from).mul(t)));
+        }
+    }
+}
+
+/// Returns the centroid of a simple polygon.
+///
+/// Returns `None` if the polygon's area is zero. The calculation is inaccurate.
+pub fn polygon_centroid<S>(polygon: &[Point2<S>]) -> Option<Point2<S>>
+where
+    S: SpadeNum + Float,
+{
+    let origin = *polygon.first()?;
+    let mut double_area = S::zero();
+    let mut sum = Point2::new(S::zero(), S::zero());
+
+    // Positions are relative to the first vertex to reduce rounding errors
+    for (index, from) in polygon.iter().enumerate() {
+        let from = from.sub(origin);
+        let to = polygon[(index + 1) % polygon.len()].sub(origin);
+        let cross = from.x * to.y - to.x * from.y;
+        double_area = double_area + cross;
+        sum = sum.add(from.add(to).mul(cross));
+    }
+
+    if double_area == S::zero() {
+        return None;
+    }
+
+    let three = S::one() + S::one() + S::one();
+    Some(origin.add(sum.mul(S::one() / (three * double_area))))
+}
+
 pub fn distance_2_triangle<S>(vertices: [Point2<S>; 3], query_point: Point2<S>) -> S
 where
     S: SpadeNum + Float,
@@ -387,12 +626,170 @@ where
     (b.x * c.y - b.y * c.x).abs() * 0.5.into()
 }
 
+/// Returns the inner angles of a triangle in radians.
+///
+/// The angle at index `i` belongs to the corner at `positions[i]`.
+pub fn triangle_angles<S>(positions: [Point2<S>; 3]) -> [S; 3]
+where
+    S: SpadeNum + Float,
+{
+    let angle = |apex: Point2<S>, p0: Point2<S>, p1: Point2<S>| {
+        let a = p0.sub(apex);
+        let b = p1.sub(apex);
+        let cross = a.x * b.y - a.y * b.x;
+        cross.abs().atan2(a.dot(b))
+    };
+    let [v0, v1, v2] = positions;
+    [angle(v0, v1, v2), angle(v1, v2, v0), angle(v2, v0, v1)]
+}
+
 #[cfg(test)]
 mod test {
     use super::{mitigate_underflow_for_coordinate, validate_coordinate};
     use crate::{InsertionError, Point2};
     use approx::assert_relative_eq;
 
+    #[test]
+    fn test_line_intersection() {
+        use super::line_intersection;
+
+        let result = line_intersection(
+            Point2::new(0.0f64, 0.0),
+            Point2::new(2.0, 2.0),
+            Point2::new(0.0, 1.0),
+            Point2::new(4.0, 1.0),
+        );
+        assert_eq!(result, Point2::new(1.0, 1.0));
+
+        let result = line_intersection(
+            Point2::new(0.0f64, -1.0),
+            Point2::new(0.0, 1.0),
+            Point2::new(-1.0, 0.5),
+            Point2::new(3.0, -1.5),
+        );
+        assert_relative_eq!(result.x, 0.0);
+        assert_relative_eq!(result.y, 0.0);
+    }
+
+    #[test]
+    fn test_clip_polygon_with_bisector() {
+        use super::clip_polygon_with_bisector;
+
+        let square = [
+            Point2::new(0.0f64, 0.0),
+            Point2::new(2.0, 0.0),
+            Point2::new(2.0, 2.0),
+            Point2::new(0.0, 2.0),
+        ];
+
+        let mut result = alloc::vec::Vec::new();
+        clip_polygon_with_bisector(
+            &square,
+            Point2::new(0.0, 1.0),
+            Point2::new(2.0, 1.0),
+            &mut result,
+        );
+        assert_eq!(
+            result,
+            alloc::vec![
+                Point2::new(0.0, 0.0),
+                Point2::new(1.0, 0.0),
+                Point2::new(1.0, 2.0),
+                Point2::new(0.0, 2.0),
+            ]
+        );
+
+        // The bisector doesn't intersect the square
+        clip_polygon_with_bisector(
+            &square,
+            Point2::new(1.0, 1.0),
+            Point2::new(5.0, 1.0),
+            &mut result,
+        );
+        assert_eq!(result, square);
+
+        clip_polygon_with_bisector(
+            &square,
+            Point2::new(5.0, 1.0),
+            Point2::new(1.0, 1.0),
+            &mut result,
+        );
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    fn test_clip_polygon_with_power_bisector() {
+        use super::clip_polygon_with_power_bisector;
+
+        let square = [
+            Point2::new(-2.0f64, -2.0),
+            Point2::new(2.0, -2.0),
+            Point2::new(2.0, 2.0),
+            Point2::new(-2.0, 2.0),
+        ];
+
+        // The heavier site gets the larger part
+        let mut result = alloc::vec::Vec::new();
+        clip_polygon_with_power_bisector(
+            &square,
+            (Point2::new(-1.0, 0.0), 2.0),
+            (Point2::new(1.0, 0.0), 0.0),
+            &mut result,
+        );
+        assert_eq!(
+            result,
+            alloc::vec![
+                Point2::new(-2.0, -2.0),
+                Point2::new(0.5, -2.0),
+                Point2::new(0.5, 2.0),
+                Point2::new(-2.0, 2.0),
+            ]
+        );
+
+        // Equal weights result in the regular bisector
+        clip_polygon_with_power_bisector(
+            &square,
+            (Point2::new(1.0, 0.0), 3.0),
+            (Point2::new(-1.0, 0.0), 3.0),
+            &mut result,
+        );
+        assert_eq!(
+            result,
+            alloc::vec![
+                Point2::new(0.0, -2.0),
+                Point2::new(2.0, -2.0),
+                Point2::new(2.0, 2.0),
+                Point2::new(0.0, 2.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_polygon_centroid() {
+        use super::polygon_centroid;
+
+        let square = [
+            Point2::new(1.0f64, 1.0),
+            Point2::new(3.0, 1.0),
+            Point2::new(3.0, 3.0),
+            Point2::new(1.0, 3.0),
+        ];
+        assert_eq!(polygon_centroid(&square), Some(Point2::new(2.0, 2.0)));
+
+        let triangle = [
+            Point2::new(0.0f64, 0.0),
+            Point2::new(0.0, 3.0),
+            Point2::new(3.0, 0.0),
+        ];
+        let centroid = polygon_centroid(&triangle).unwrap();
+        assert_relative_eq!(centroid.x, 1.0);
+        assert_relative_eq!(centroid.y, 1.0);
+
+        assert_eq!(polygon_centroid::<f64>(&[]), None);
+        let line = [Point2::new(0.0f64, 0.0), Point2::new(1.0, 1.0)];
+        assert_eq!(polygon_centroid(&line), None);
+    }
+
     #[test]
     fn test_point_projection() {
         use super::project_point;
@@ -601,6 +998,21 @@ mod test {
         assert!(distance_2_triangle(t, Point2::new(0.6, 0.6)) > 0.001);
     }
 
+    #[test]
+    fn test_triangle_angles() {
+        use super::triangle_angles;
+        use core::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+
+        let angles = triangle_angles([
+            Point2::new(0.0, 0.0),
+            Point2::new(1.0, 0.0),
+            Point2::new(0.0, 1.0),
+        ]);
+        assert_relative_eq!(angles[0], FRAC_PI_2);
+        assert_relative_eq!(angles[1], FRAC_PI_4);
+        assert_relative_eq!(angles[2], FRAC_PI_4);
+    }
+
     #[test]
     fn test_contained_in_circumference() {
         use super::contained_in_circumference;
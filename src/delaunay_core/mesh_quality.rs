@@ -0,0 +1,267 @@
+#[cfg(not(feature = "std"))]
+use hashbrown::HashSet;
+#[cfg(feature = "std")]
+use std::collections::HashSet;
+
+use num_traits::Float;
+
+use crate::{HasPosition, SpadeNum, Triangulation};
+
+use super::{math, FixedFaceHandle, InnerTag};
+
+/// The number of bins of the angle histograms contained in a [QualityReport].
+///
+/// Each bin covers a range of 10 degrees.
+pub const NUM_ANGLE_HISTOGRAM_BINS: usize = 18;
+
+/// Summarizes the distribution of a quality measure over all faces of a [QualityReport].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct QualityDistribution<S> {
+    /// The smallest value of any face.
+    pub min: S,
+    /// The largest value of any face.
+    pub max: S,
+    /// The mean value over all faces.
+    pub mean: S,
+}
+
+/// Describes the quality of a triangulation's mesh.
+///
+/// The report can be used to check if a mesh fulfills some quality requirements, e.g. after a
+/// [refinement](crate::ConstrainedDelaunayTriangulation::refine). All distributions and histograms
+/// are calculated over the inner faces of a triangulation. If the report contains no faces, all
+/// distributions are set to zero.
+///
+/// All angles are given in radians.
+///
+/// *See [FloatTriangulation::quality_report](crate::FloatTriangulation::quality_report) and
+/// [ConstrainedDelaunayTriangulation::smooth_steiner_points](crate::ConstrainedDelaunayTriangulation::smooth_steiner_points)*
+#[derive(Debug, Clone, PartialEq)]
+pub struct QualityReport<S> {
+    /// The number of faces contained in this report.
+    pub num_faces: usize,
+
+    /// The distribution of each face's smallest inner angle.
+    pub min_angle: QualityDistribution<S>,
+
+    /// The distribution of each face's largest inner angle.
+    pub max_angle: QualityDistribution<S>,
+
+    /// Counts the faces by their smallest inner angle.
+    ///
+    /// Bin `i` counts all faces whose smallest angle lies within `[10 * i, 10 * (i + 1))` degrees.
+    pub min_angle_histogram: [usize; NUM_ANGLE_HISTOGRAM_BINS],
+
+    /// Counts the faces by their largest inner angle.
+    ///
+    /// Bin `i` counts all faces whose largest angle lies within `[10 * i, 10 * (i + 1))` degrees.
+    /// Angles of 180 degrees are counted by the last bin.
+    pub max_angle_histogram: [usize; NUM_ANGLE_HISTOGRAM_BINS],
+
+    /// The distribution of each face's aspect ratio.
+    ///
+    /// The aspect ratio is the length of a face's longest edge divided by its shortest altitude.
+    /// Equilateral triangles have the smallest possible aspect ratio of `2 / sqrt(3) ≈ 1.155`.
+    pub aspect_ratio: QualityDistribution<S>,
+
+    /// The distribution of each face's circumradius to shortest edge ratio.
+    ///
+    /// This is the quality measure used by [refinement](crate::ConstrainedDelaunayTriangulation::refine),
+    /// see [AngleLimit](crate::AngleLimit::from_radius_to_shortest_edge_ratio). Equilateral
+    /// triangles have the smallest possible ratio of `1 / sqrt(3) ≈ 0.577`.
+    pub radius_edge_ratio: QualityDistribution<S>,
+
+    /// The distribution of face areas.
+    pub area: QualityDistribution<S>,
+}
+
+struct DistributionBuilder<S> {
+    min: S,
+    max: S,
+    sum: S,
+}
+
+impl<S: SpadeNum + Float> DistributionBuilder<S> {
+    fn new() -> Self {
+        Self {
+            min: S::infinity(),
+            max: S::neg_infinity(),
+            sum: S::zero(),
+        }
+    }
+
+    fn add(&mut self, value: S) {
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+        self.sum = self.sum + value;
+    }
+
+    fn build(self, num_faces: usize) -> QualityDistribution<S> {
+        if num_faces == 0 {
+            return QualityDistribution {
+                min: S::zero(),
+                max: S::zero(),
+                mean: S::zero(),
+            };
+        }
+
+        QualityDistribution {
+            min: self.min,
+            max: self.max,
+            mean: self.sum / (num_faces as f32).into(),
+        }
+    }
+}
+
+fn histogram_bin<S: SpadeNum + Float>(angle: S) -> usize {
+    let bin = (angle.into().to_degrees() / 10.0).floor();
+    (bin.max(0.0) as usize).min(NUM_ANGLE_HISTOGRAM_BINS - 1)
+}
+
+pub fn quality_report<T>(
+    triangulation: &T,
+    excluded_faces: &[FixedFaceHandle<InnerTag>],
+) -> QualityReport<<T::Vertex as HasPosition>::Scalar>
+where
+    T: Triangulation,
+    <T::Vertex as HasPosition>::Scalar: Float,
+{
+    let excluded_faces = excluded_faces.iter().copied().collect::<HashSet<_>>();
+
+    let mut num_faces = 0;
+    let mut min_angle_histogram = [0; NUM_ANGLE_HISTOGRAM_BINS];
+    let mut max_angle_histogram = [0; NUM_ANGLE_HISTOGRAM_BINS];
+    let mut min_angle = DistributionBuilder::new();
+    let mut max_angle = DistributionBuilder::new();
+    let mut aspect_ratio = DistributionBuilder::new();
+    let mut radius_edge_ratio = DistributionBuilder::new();
+    let mut area = DistributionBuilder::new();
+
+    for face in triangulation.inner_faces() {
+        if excluded_faces.contains(&face.fix()) {
+            continue;
+        }
+
+        let positions = face.positions();
+        let [a0, a1, a2] = math::triangle_angles(positions);
+        let face_min_angle = a0.min(a1).min(a2);
+        let face_max_angle = a0.max(a1).max(a2);
+
+        let [p0, p1, p2] = positions;
+        let [l0, l1, l2] = [p0.distance_2(p1), p1.distance_2(p2), p2.distance_2(p0)];
+        let shortest_2 = l0.min(l1).min(l2);
+        let longest_2 = l0.max(l1).max(l2);
+
+        let face_area = math::triangle_area(positions);
+        let (_, radius_2) = math::circumcenter(positions);
+
+        // The shortest altitude belongs to the longest edge: altitude = 2 * area / longest
+        let two: <T::Vertex as HasPosition>::Scalar = 2.0f32.into();
+        let face_aspect_ratio = longest_2 / (two * face_area);
+
+        min_angle_histogram[histogram_bin(face_min_angle)] += 1;
+        max_angle_histogram[histogram_bin(face_max_angle)] += 1;
+        min_angle.add(face_min_angle);
+        max_angle.add(face_max_angle);
+        aspect_ratio.add(face_aspect_ratio);
+        radius_edge_ratio.add((radius_2 / shortest_2).sqrt());
+        area.add(face_area);
+        num_faces += 1;
+    }
+
+    QualityReport {
+        num_faces,
+        min_angle: min_angle.build(num_faces),
+        max_angle: max_angle.build(num_faces),
+        min_angle_histogram,
+        max_angle_histogram,
+        aspect_ratio: aspect_ratio.build(num_faces),
+        radius_edge_ratio: radius_edge_ratio.build(num_faces),
+        area: area.build(num_faces),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::vec;
+    use approx::assert_relative_eq;
+
+    use crate::{
+        ConstrainedDelaunayTriangulation, DelaunayTriangulation, FloatTriangulation as _,
+        InsertionError, Point2, RefinementParameters, Triangulation as _,
+    };
+
+    #[test]
+    fn test_quality_report() -> Result<(), InsertionError> {
+        let triangulation = DelaunayTriangulation::<Point2<f64>>::bulk_load(vec![
+            Point2::new(0.0, 0.0),
+            Point2::new(2.0, 0.0),
+            Point2::new(1.0, 3.0f64.sqrt()),
+        ])?;
+
+        let report = triangulation.quality_report();
+        assert_eq!(report.num_faces, 1);
+        assert_relative_eq!(report.min_angle.min.to_degrees(), 60.0);
+        assert_relative_eq!(report.max_angle.max.to_degrees(), 60.0);
+        assert_relative_eq!(report.aspect_ratio.mean, 2.0 / 3.0f64.sqrt());
+        assert_relative_eq!(report.radius_edge_ratio.mean, 1.0 / 3.0f64.sqrt());
+        assert_relative_eq!(report.area.mean, 3.0f64.sqrt());
+
+        // Angles: 71.6°, 36.9° and 71.6°
+        let triangulation = DelaunayTriangulation::<Point2<f64>>::bulk_load(vec![
+            Point2::new(0.0, 0.0),
+            Point2::new(5.0, 0.0),
+            Point2::new(1.0, 3.0),
+        ])?;
+        let report = triangulation.quality_report();
+        let mut expected_min_histogram = [0; 18];
+        expected_min_histogram[3] = 1;
+        let mut expected_max_histogram = [0; 18];
+        expected_max_histogram[7] = 1;
+        assert_eq!(report.min_angle_histogram, expected_min_histogram);
+        assert_eq!(report.max_angle_histogram, expected_max_histogram);
+
+        let empty = DelaunayTriangulation::<Point2<f64>>::new().quality_report();
+        assert_eq!(empty.num_faces, 0);
+        assert_eq!(empty.area.max, 0.0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_quality_report_excluding() -> Result<(), InsertionError> {
+        let mut cdt = ConstrainedDelaunayTriangulation::<Point2<f64>>::new();
+        cdt.add_constraint_edges(
+            [
+                Point2::new(0.0, 0.0),
+                Point2::new(10.0, 0.0),
+                Point2::new(10.0, 10.0),
+                Point2::new(5.0, 1.0),
+                Point2::new(0.0, 10.0),
+            ],
+            true,
+        )?;
+
+        let result = cdt.refine(RefinementParameters::new().exclude_outer_faces(true));
+        assert!(result.refinement_complete);
+
+        let report = cdt.quality_report_excluding(&result.excluded_faces);
+        assert_eq!(
+            report.num_faces,
+            cdt.num_inner_faces() - result.excluded_faces.len()
+        );
+        assert_eq!(
+            report.min_angle_histogram.iter().sum::<usize>(),
+            report.num_faces
+        );
+        assert!(report.area.min <= report.area.mean);
+        assert!(report.area.mean <= report.area.max);
+
+        // All faces except those adjacent to the small input angles must respect the angle limit
+        let num_skinny_faces: usize = report.min_angle_histogram[..2].iter().sum();
+        assert!(num_skinny_faces <= 2);
+
+        let full_report = cdt.quality_report();
+        assert_eq!(full_report.num_faces, cdt.num_inner_faces());
+        Ok(())
+    }
+}
@@ -8,14 +8,20 @@ pub mod dcel_operations;
 mod handles;
 mod hint_generator;
 mod line_side_info;
+mod mesh_quality;
+#[cfg(feature = "rayon")]
+mod parallel_bulk_load;
 mod triangulation_ext;
 
 pub mod refinement;
+mod smoothing;
 
 pub mod interpolation;
 pub mod math;
 
-pub use bulk_load::bulk_load;
+pub use bulk_load::{bulk_load, bulk_load_stable, bulk_load_with_callback, insert_bulk};
+#[cfg(feature = "rayon")]
+pub use parallel_bulk_load::parallel_bulk_load;
 
 pub use triangulation_ext::{RemovalResult, TriangulationExt};
 
@@ -25,7 +31,15 @@ pub use hint_generator::{
     LastUsedVertexHintGenerator,
 };
 
-pub use refinement::{AngleLimit, RefinementParameters, RefinementResult};
+pub use refinement::{
+    AngleLimit, ConstraintSplit, RefinementEvent, RefinementHint, RefinementParameters,
+    RefinementProgress, RefinementResult,
+};
+
+pub use mesh_quality::{
+    quality_report, QualityDistribution, QualityReport, NUM_ANGLE_HISTOGRAM_BINS,
+};
+pub use smoothing::SmoothingResult;
 
 pub use line_side_info::LineSideInfo;
 
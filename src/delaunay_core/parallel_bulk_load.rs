@@ -0,0 +1,566 @@
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use rayon::prelude::*;
+
+use crate::{HasPosition, InsertionError, Point2, SpadeNum, Triangulation};
+
+use super::dcel_operations;
+use super::{bulk_load, math, Dcel, FixedDirectedEdgeHandle, FixedUndirectedEdgeHandle};
+use super::{FixedVertexHandle, LineSideInfo};
+
+/// Minimum number of vertices per part. Smaller inputs are not worth the overhead of being split.
+const MIN_PART_SIZE: usize = 1 << 14;
+
+/// Bulk loads a triangulation by splitting the input into several parts which are triangulated
+/// in parallel.
+///
+/// # Overview
+///
+///  1. Sort all vertices by their x coordinate.
+///  2. Split the sorted vertices into vertical strips. Strips are chosen such that no two strips
+///     share a vertex with the same x coordinate and such that each strip contains at least three
+///     non-collinear vertices.
+///  3. Bulk load each strip in parallel with [bulk_load].
+///  4. Move all strip triangulations into a single DCEL.
+///  5. Stitch adjacent strips together, beginning with the left most strip. As both strips are
+///     convex and separated by a vertical line, the gap between them can be filled by "zipping"
+///     up their hulls from their lower to their upper common tangent.
+///  6. The stitched triangulation is not necessarily Delaunay close to the seams. Flip all
+///     illegal edges until the Delaunay property is restored.
+pub fn parallel_bulk_load<V, T>(elements: Vec<V>) -> Result<T, InsertionError>
+where
+    V: HasPosition + Send,
+    T: Triangulation<Vertex = V> + Send,
+{
+    let num_threads = rayon::current_num_threads();
+    if num_threads <= 1 {
+        return bulk_load(elements);
+    }
+
+    let num_parts = num_threads * 4;
+    let part_size = (elements.len() / num_parts).max(MIN_PART_SIZE);
+    parallel_bulk_load_with_part_size(elements, part_size)
+}
+
+fn parallel_bulk_load_with_part_size<V, T>(
+    mut elements: Vec<V>,
+    part_size: usize,
+) -> Result<T, InsertionError>
+where
+    V: HasPosition + Send,
+    T: Triangulation<Vertex = V> + Send,
+{
+    for element in &elements {
+        crate::validate_vertex(element)?;
+    }
+
+    // Stable sort: Keeps the relative order of vertices with identical positions intact.
+    elements.par_sort_by(|l, r| l.position().x.partial_cmp(&r.position().x).unwrap());
+
+    let part_ends = calculate_part_ends(&elements, part_size);
+
+    if part_ends.len() <= 1 {
+        return bulk_load(elements);
+    }
+
+    let mut parts = Vec::with_capacity(part_ends.len());
+    for start in part_ends.iter().rev().skip(1) {
+        parts.push(elements.split_off(*start));
+    }
+    parts.push(elements);
+    parts.reverse();
+
+    let triangulations = parts
+        .into_par_iter()
+        .map(bulk_load::<V, T>)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let [num_vertices, num_undirected_edges, num_faces] =
+        triangulations[1..]
+            .iter()
+            .fold([0; 3], |[v, e, f], triangulation| {
+                [
+                    v + triangulation.num_vertices(),
+                    e + triangulation.num_undirected_edges(),
+                    f + triangulation.num_all_faces(),
+                ]
+            });
+
+    let mut triangulations = triangulations.into_iter();
+    let mut result = triangulations.next().unwrap();
+    result
+        .s_mut()
+        .reserve_capacity(num_vertices, num_undirected_edges, num_faces);
+
+    let mut left_part = 0..result.num_vertices();
+    let mut edges_to_legalize = Vec::new();
+
+    for mut triangulation in triangulations {
+        let part = core::mem::take(triangulation.s_mut());
+        let right_part = left_part.end..left_part.end + part.num_vertices();
+
+        dcel_operations::append(result.s_mut(), part);
+        stitch(
+            result.s_mut(),
+            left_part,
+            right_part.clone(),
+            &mut edges_to_legalize,
+        );
+        left_part = right_part;
+    }
+
+    legalize_edges(result.s_mut(), edges_to_legalize);
+
+    Ok(result)
+}
+
+/// Returns the (exclusive) end index of each part.
+///
+/// `elements` must be sorted by their x coordinate.
+fn calculate_part_ends<V: HasPosition>(elements: &[V], part_size: usize) -> Vec<usize> {
+    let x = |index: usize| elements[index].position().x;
+
+    let mut result = Vec::new();
+    let mut start = 0;
+    while start < elements.len() {
+        let mut end = (start + part_size).min(elements.len());
+
+        loop {
+            // Parts must be separated by a vertical line
+            while end < elements.len() && x(end - 1) == x(end) {
+                end += 1;
+            }
+
+            // Parts must contain at least one inner face
+            match first_position_not_on_line(&elements[start..]) {
+                Some(offset) if start + offset >= end => end = start + offset + 1,
+                Some(_) => break,
+                None => {
+                    end = elements.len();
+                    break;
+                }
+            }
+        }
+
+        result.push(end);
+        start = end;
+    }
+
+    if result.len() > 1
+        && first_position_not_on_line(&elements[result[result.len() - 2]..]).is_none()
+    {
+        // Merge the last part into its predecessor
+        result.remove(result.len() - 2);
+    }
+
+    result
+}
+
+/// Returns the index of the first element that is not collinear with all previous elements.
+fn first_position_not_on_line<V: HasPosition>(elements: &[V]) -> Option<usize> {
+    let first = elements.first()?.position();
+
+    let second = elements
+        .iter()
+        .map(|element| element.position())
+        .find(|position| *position != first)?;
+
+    elements
+        .iter()
+        .position(|element| !math::side_query(first, second, element.position()).is_on_line())
+}
+
+/// Fills the gap between two adjacent parts.
+///
+/// `left_part` and `right_part` contain the vertex indices of the last two parts. All previous parts
+/// must already be stitched to `left_part`. Both parts must be separated by a vertical line.
+///
+/// All edges that may need to be flipped are added to `edges_to_legalize`.
+fn stitch<V, DE, UE, F>(
+    dcel: &mut Dcel<V, DE, UE, F>,
+    left_part: Range<usize>,
+    right_part: Range<usize>,
+    edges_to_legalize: &mut Vec<FixedUndirectedEdgeHandle>,
+) where
+    V: HasPosition,
+    DE: Default,
+    UE: Default,
+    F: Default,
+{
+    let position =
+        |dcel: &Dcel<V, DE, UE, F>, vertex: FixedVertexHandle| dcel.vertex(vertex).position();
+
+    let compare_x = |v0: &FixedVertexHandle, v1: &FixedVertexHandle| {
+        let x0 = position(dcel, *v0).x;
+        let x1 = position(dcel, *v1).x;
+        x0.partial_cmp(&x1).unwrap()
+    };
+
+    // Start with the right most vertex of the left part and the left most vertex of the right part
+    let l = left_part
+        .map(FixedVertexHandle::new)
+        .max_by(compare_x)
+        .unwrap();
+    let r = right_part
+        .map(FixedVertexHandle::new)
+        .min_by(compare_x)
+        .unwrap();
+
+    let (lower_l, lower_r) = find_tangent(dcel, l, r, true);
+    let (upper_l, upper_r) = find_tangent(dcel, l, r, false);
+
+    // The hull edges facing the gap. Both tangents may touch a part in the same vertex, in this
+    // case its whole hull faces the gap.
+    let mut remaining_left = hull_distance(dcel, upper_l, lower_l);
+    let mut remaining_right = hull_distance(dcel, lower_r, upper_r);
+
+    // Gap edges are directed from the left to the right part and have the gap on their left side.
+    let mut base = dcel_operations::connect_outer_edges(
+        dcel,
+        outer_in_edge(dcel, lower_l),
+        outer_in_edge(dcel, lower_r),
+    );
+
+    while remaining_left > 0 || remaining_right > 0 {
+        // Try to add either the triangle (l, r, next_r) or (l, r, next_l)
+        //
+        //  next_l  next_r
+        //     |       ^
+        //     |       |
+        //     v       |
+        //     l ----> r
+        //       base
+        let base_entry = dcel.directed_edge(base);
+        let [l, r] = base_entry.vertices().map(|vertex| vertex.position());
+        let next_r = base_entry.next().to().position();
+        let next_l = base_entry.prev().from().position();
+
+        // A new edge is valid if it lies within the gap's interior angle at its origin
+        let can_advance_right =
+            remaining_right > 0 && (remaining_left == 0 || is_inside_angle(l, r, next_l, next_r));
+
+        let can_advance_left =
+            remaining_left > 0 && (remaining_right == 0 || is_inside_angle(r, next_r, l, next_l));
+
+        let advance_right = match (can_advance_left, can_advance_right) {
+            (true, true) => {
+                // Prefer the triangle that is locally Delaunay to reduce the number of flips
+                !math::contained_in_circumference(l, r, next_r, next_l)
+            }
+            (false, true) => true,
+            (true, false) => false,
+            (false, false) => panic!("Unexpected geometry. This is a bug in spade."),
+        };
+
+        edges_to_legalize.push(base.as_undirected());
+        if advance_right {
+            edges_to_legalize.push(base_entry.next().fix().as_undirected());
+            base = dcel_operations::create_single_face_between_edge_and_next(dcel, base);
+            remaining_right -= 1;
+        } else {
+            let prev = base_entry.prev().fix();
+            edges_to_legalize.push(prev.as_undirected());
+            base = dcel_operations::create_single_face_between_edge_and_next(dcel, prev);
+            remaining_left -= 1;
+        }
+        edges_to_legalize.push(base.as_undirected());
+    }
+}
+
+/// Returns the number of hull edges between two vertices when walking the hull in clockwise
+/// direction.
+///
+/// Walks around the whole hull if both vertices are identical.
+fn hull_distance<V, DE, UE, F>(
+    dcel: &Dcel<V, DE, UE, F>,
+    from: FixedVertexHandle,
+    to: FixedVertexHandle,
+) -> usize {
+    let mut edge = dcel.directed_edge(outer_in_edge(dcel, from)).next();
+    let mut result = 1;
+    while edge.to().fix() != to {
+        edge = edge.next();
+        result += 1;
+    }
+    result
+}
+
+/// Returns `true` if `query` lies strictly inside the angle that is swept when rotating `from`
+/// counterclockwise around `apex` until it reaches `to`.
+fn is_inside_angle<S: SpadeNum>(
+    apex: Point2<S>,
+    from: Point2<S>,
+    to: Point2<S>,
+    query: Point2<S>,
+) -> bool {
+    let after_from = math::side_query(apex, from, query).is_on_left_side();
+    let before_to = math::side_query(apex, to, query).is_on_right_side();
+
+    if math::side_query(apex, from, to).is_on_left_side() {
+        // Convex angle
+        after_from && before_to
+    } else {
+        // Reflex angle
+        after_from || before_to
+    }
+}
+
+/// Returns the edge of the outer face that points to `vertex`.
+///
+/// The vertex must appear only once on the convex hull.
+fn outer_in_edge<V, DE, UE, F>(
+    dcel: &Dcel<V, DE, UE, F>,
+    vertex: FixedVertexHandle,
+) -> FixedDirectedEdgeHandle {
+    dcel.vertex(vertex)
+        .out_edges()
+        .find(|edge| edge.is_outer_edge())
+        .expect("Expected a vertex on the convex hull. This is a bug in spade.")
+        .prev()
+        .fix()
+}
+
+/// Finds the lower (or upper) common tangent of two convex parts that are separated by a vertical
+/// line.
+///
+/// `l` must be a vertex on the hull of the left part and `r` a vertex on the hull of the right
+/// part. Both parts must be non-degenerate.
+///
+/// The returned vertices are chosen such that all vertices lie on the left side of the lower
+/// tangent (or right side of the upper tangent). If several vertices lie on the tangent, the
+/// innermost vertex of each part is returned.
+fn find_tangent<V, DE, UE, F>(
+    dcel: &Dcel<V, DE, UE, F>,
+    mut l: FixedVertexHandle,
+    mut r: FixedVertexHandle,
+    lower: bool,
+) -> (FixedVertexHandle, FixedVertexHandle)
+where
+    V: HasPosition,
+{
+    // Outer edges circle the hull in clockwise order. Hence, the next vertex goes down on the
+    // right side of the left part and up on the left side of the right part.
+    let next = |vertex: FixedVertexHandle| {
+        let edge = dcel.directed_edge(outer_in_edge(dcel, vertex));
+        edge.next().to().fix()
+    };
+    let prev = |vertex: FixedVertexHandle| {
+        let edge = dcel.directed_edge(outer_in_edge(dcel, vertex));
+        edge.from().fix()
+    };
+    let position = |vertex: FixedVertexHandle| dcel.vertex(vertex).position();
+
+    let is_outside = |side: LineSideInfo| {
+        if lower {
+            side.is_on_right_side()
+        } else {
+            side.is_on_left_side()
+        }
+    };
+
+    let should_move =
+        |l: FixedVertexHandle, r: FixedVertexHandle, candidate: Point2<_>, inwards| {
+            let side = math::side_query(position(l), position(r), candidate);
+            is_outside(side) || side.is_on_line() && inwards
+        };
+
+    loop {
+        let mut changed = false;
+
+        loop {
+            let candidate = if lower { next(l) } else { prev(l) };
+            let candidate_pos = position(candidate);
+            if !should_move(l, r, candidate_pos, candidate_pos.x > position(l).x) {
+                break;
+            }
+            l = candidate;
+            changed = true;
+        }
+
+        loop {
+            let candidate = if lower { prev(r) } else { next(r) };
+            let candidate_pos = position(candidate);
+            if !should_move(l, r, candidate_pos, candidate_pos.x < position(r).x) {
+                break;
+            }
+            r = candidate;
+            changed = true;
+        }
+
+        if !changed {
+            return (l, r);
+        }
+    }
+}
+
+/// Flips illegal edges until all edges fulfill the Delaunay property again.
+fn legalize_edges<V, DE, UE, F>(
+    dcel: &mut Dcel<V, DE, UE, F>,
+    mut edges_to_legalize: Vec<FixedUndirectedEdgeHandle>,
+) where
+    V: HasPosition,
+{
+    while let Some(edge) = edges_to_legalize.pop() {
+        let edge = dcel.directed_edge(edge.as_directed());
+
+        let (Some(left), Some(right)) = (edge.opposite_position(), edge.rev().opposite_position())
+        else {
+            continue;
+        };
+
+        if math::contained_in_circumference(
+            edge.from().position(),
+            edge.to().position(),
+            left,
+            right,
+        ) {
+            edges_to_legalize.extend(
+                [
+                    edge.next(),
+                    edge.prev(),
+                    edge.rev().next(),
+                    edge.rev().prev(),
+                ]
+                .map(|edge| edge.fix().as_undirected()),
+            );
+
+            dcel_operations::flip_cw(dcel, edge.fix().as_undirected());
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::vec::Vec;
+
+    use crate::test_utilities::{random_points_with_seed, SEED};
+    use crate::{
+        ConstrainedDelaunayTriangulation, DelaunayTriangulation, InsertionError, Point2,
+        Triangulation, TriangulationExt,
+    };
+
+    use super::parallel_bulk_load_with_part_size;
+
+    fn check_parallel_bulk_load(
+        vertices: Vec<Point2<f64>>,
+        part_size: usize,
+    ) -> Result<(), InsertionError> {
+        let expected = DelaunayTriangulation::<_>::bulk_load(vertices.clone())?;
+        let triangulation: DelaunayTriangulation<_> =
+            parallel_bulk_load_with_part_size(vertices, part_size)?;
+
+        triangulation.sanity_check();
+        assert_eq!(triangulation.num_vertices(), expected.num_vertices());
+        assert_eq!(triangulation.num_inner_faces(), expected.num_inner_faces());
+        assert_eq!(
+            triangulation.num_undirected_edges(),
+            expected.num_undirected_edges()
+        );
+
+        for edge in triangulation.undirected_edges() {
+            if let (Some(left), Some(right)) = (
+                edge.as_directed().opposite_position(),
+                edge.as_directed().rev().opposite_position(),
+            ) {
+                let [from, to] = edge.positions();
+                assert!(!crate::delaunay_core::math::contained_in_circumference(
+                    from, to, left, right
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    fn edge_set(triangulation: &DelaunayTriangulation<Point2<f64>>) -> Vec<[[u64; 2]; 2]> {
+        let to_bits = |position: Point2<f64>| [position.x.to_bits(), position.y.to_bits()];
+        let mut result: Vec<_> = triangulation
+            .undirected_edges()
+            .map(|edge| {
+                let [from, to] = edge.positions().map(to_bits);
+                if from < to {
+                    [from, to]
+                } else {
+                    [to, from]
+                }
+            })
+            .collect();
+        result.sort_unstable();
+        result
+    }
+
+    #[test]
+    fn test_parallel_bulk_load_random() -> Result<(), InsertionError> {
+        for part_size in [3, 10, 100, 1000, 20000] {
+            check_parallel_bulk_load(random_points_with_seed(5000, SEED), part_size)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_parallel_bulk_load_equals_bulk_load() -> Result<(), InsertionError> {
+        let vertices = random_points_with_seed(3000, SEED);
+        let expected = DelaunayTriangulation::<_>::bulk_load(vertices.clone())?;
+        let triangulation: DelaunayTriangulation<_> =
+            parallel_bulk_load_with_part_size(vertices, 200)?;
+        assert_eq!(edge_set(&triangulation), edge_set(&expected));
+        Ok(())
+    }
+
+    #[test]
+    fn test_parallel_bulk_load_grid() -> Result<(), InsertionError> {
+        let mut vertices = Vec::new();
+        for x in 0..40 {
+            for y in 0..40 {
+                vertices.push(Point2::new(x as f64, y as f64));
+            }
+        }
+        for part_size in [1, 5, 41, 100, 500] {
+            check_parallel_bulk_load(vertices.clone(), part_size)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_parallel_bulk_load_degenerate() -> Result<(), InsertionError> {
+        // Many vertices on a single line, a few scattered duplicates
+        let mut vertices: Vec<_> = (0..100).map(|x| Point2::new(x as f64, 0.0)).collect();
+        vertices.extend((0..100).map(|x| Point2::new(x as f64, 0.0)));
+        vertices.push(Point2::new(50.0, 3.0));
+        vertices.push(Point2::new(-20.0, 1.0));
+        vertices.push(Point2::new(120.0, -1.0));
+        vertices.push(Point2::new(120.0, 5.0));
+        for part_size in [1, 3, 10, 50] {
+            check_parallel_bulk_load(vertices.clone(), part_size)?;
+        }
+
+        // All vertices on a line
+        let vertices: Vec<_> = (0..100)
+            .map(|x| Point2::new(x as f64, 2.0 * x as f64))
+            .collect();
+        check_parallel_bulk_load(vertices, 10)?;
+
+        check_parallel_bulk_load(Vec::new(), 10)?;
+        check_parallel_bulk_load(alloc::vec![Point2::new(1.0, 2.0)], 1)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_parallel_bulk_load_cdt() -> Result<(), InsertionError> {
+        let vertices = random_points_with_seed(1000, SEED);
+        let cdt: ConstrainedDelaunayTriangulation<_> =
+            parallel_bulk_load_with_part_size(vertices, 100)?;
+        cdt.cdt_sanity_check();
+        assert_eq!(cdt.num_vertices(), 1000);
+        assert_eq!(cdt.num_constraints(), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parallel_bulk_load_invalid_input() {
+        let vertices = alloc::vec![Point2::new(0.0, 1.0), Point2::new(f64::NAN, 0.0)];
+        let result: Result<DelaunayTriangulation<_>, _> =
+            parallel_bulk_load_with_part_size(vertices, 1);
+        assert_eq!(result.unwrap_err(), InsertionError::NAN);
+    }
+}
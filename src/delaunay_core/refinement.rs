@@ -4,7 +4,9 @@ use hashbrown::{HashMap, HashSet};
 use std::collections::{HashMap, HashSet};
 
 use alloc::collections::VecDeque;
+use alloc::sync::Arc;
 use alloc::vec::Vec;
+use core::ops::ControlFlow;
 
 use num_traits::Float;
 
@@ -14,14 +16,15 @@ use crate::{
 };
 
 use super::{
-    DirectedEdgeHandle, FaceHandle, FixedFaceHandle, FixedUndirectedEdgeHandle, FixedVertexHandle,
-    InnerTag, TriangulationExt, UndirectedEdgeHandle,
+    DirectedEdgeHandle, FaceHandle, FixedDirectedEdgeHandle, FixedFaceHandle,
+    FixedUndirectedEdgeHandle, FixedVertexHandle, InnerTag, TriangulationExt, UndirectedEdgeHandle,
 };
 
 /// Contains details about the outcome of a refinement procedure.
 ///
 /// *See [ConstrainedDelaunayTriangulation::refine]*
 #[derive(Debug, Clone)]
+#[non_exhaustive]
 pub struct RefinementResult {
     /// A `Vec` containing all outer faces that were excluded from refinement.
     ///
@@ -35,7 +38,74 @@ pub struct RefinementResult {
     /// This will be `false` if the refinement ran out of additional vertices
     /// (see [RefinementParameters::with_max_additional_vertices]). Consider adapting the refinement parameters in this case,
     /// either by using a higher additional vertex count or by e.g. lowering the [angle limit](RefinementParameters::with_angle_limit).
+    /// It will also be `false` if the refinement has been [cancelled](Self::cancelled).
     pub refinement_complete: bool,
+
+    /// Set to `true` if the refinement was stopped early by the
+    /// [progress callback](RefinementParameters::with_progress_callback).
+    ///
+    /// The triangulation remains a valid CDT in this case. It contains all vertices that were inserted
+    /// up to that point.
+    pub cancelled: bool,
+
+    /// All vertices that were inserted by the refinement (steiner points), in insertion order.
+    ///
+    /// Any vertex not contained in this list is an input vertex.
+    pub inserted_vertices: Vec<FixedVertexHandle>,
+
+    /// Lists all constraint edges that were split during refinement.
+    ///
+    /// This can be used to propagate any data stored on the original constraint edges to their sub edges.
+    pub split_constraints: Vec<ConstraintSplit>,
+}
+
+/// Describes how a constraint edge was split into multiple sub edges during refinement.
+///
+/// *See [RefinementResult::split_constraints]*
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ConstraintSplit {
+    /// The two input vertices of the original constraint edge.
+    pub original_vertices: [FixedVertexHandle; 2],
+
+    /// The constraint edges that replace the original edge.
+    ///
+    /// The edges are ordered and oriented from `original_vertices[0]` towards `original_vertices[1]`.
+    /// All their inner vertices are steiner points.
+    pub sub_edges: Vec<FixedDirectedEdgeHandle>,
+}
+
+/// Describes which kind of refinement step has just been performed.
+///
+/// *See [RefinementProgress]*
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum RefinementEvent {
+    /// A steiner point was inserted at the circumcenter of a face.
+    SteinerPointInserted,
+    /// An encroached constraint edge or convex hull edge was split by inserting a new vertex on it.
+    SegmentSplit,
+}
+
+/// Reports the progress of a refinement operation.
+///
+/// Passed to the callback specified with [RefinementParameters::with_progress_callback].
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct RefinementProgress<S> {
+    /// The kind of refinement step that has just been performed.
+    pub event: RefinementEvent,
+
+    /// The newly inserted vertex.
+    pub new_vertex: FixedVertexHandle,
+
+    /// The position of the newly inserted vertex.
+    pub position: Point2<S>,
+
+    /// The number of vertices that have been inserted by this refinement so far.
+    pub num_additional_vertices: usize,
+
+    /// The maximum number of vertices that may be inserted by this refinement.
+    ///
+    /// *See [RefinementParameters::with_max_additional_vertices]*
+    pub max_additional_vertices: usize,
 }
 
 /// Specifies the minimum allowed angle that should be kept after a refinement procedure.
@@ -138,13 +208,50 @@ impl Default for AngleLimit {
     }
 }
 
-#[derive(Debug, PartialEq, PartialOrd, Clone, Copy, Hash)]
-enum RefinementHint {
+/// Specifies if a face should be subdivided during refinement.
+///
+/// Returned by the predicate passed to [ConstrainedDelaunayTriangulation::refine_with_predicate].
+/// Hints are ordered by their urgency: `Ignore < ShouldRefine < MustRefine`.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+pub enum RefinementHint {
+    /// The face is fine as is. It may still be split if this is required to resolve an encroached
+    /// constraint edge or if the built-in refinement criteria demand it.
     Ignore,
+    /// The face should be refined. Similar to skinny faces, the face will not be split if it is
+    /// adjacent to a small input angle that cannot be improved any further.
     ShouldRefine,
+    /// The face must be refined, regardless of its shape.
     MustRefine,
 }
 
+/// Wraps a function supplied as part of the [RefinementParameters].
+///
+/// Two functions are only considered to be equal if they refer to the same instance.
+struct UserFunction<T: ?Sized>(Arc<T>);
+
+impl<T: ?Sized> Clone for UserFunction<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T: ?Sized> PartialEq for UserFunction<T> {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl<T: ?Sized> core::fmt::Debug for UserFunction<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("<function>")
+    }
+}
+
+type SizingFunction<S> = UserFunction<dyn Fn(Point2<S>) -> S + Send + Sync>;
+
+type ProgressCallback<S> =
+    UserFunction<dyn Fn(RefinementProgress<S>) -> ControlFlow<()> + Send + Sync>;
+
 /// Controls how Delaunay refinement is performed.
 ///
 /// Refer to [ConstrainedDelaunayTriangulation::refine] and methods implemented by this type for more details
@@ -173,8 +280,12 @@ pub struct RefinementParameters<S: SpadeNum + Float> {
     angle_limit: AngleLimit,
     min_area: Option<S>,
     max_area: Option<S>,
+    max_area_function: Option<SizingFunction<S>>,
+    max_edge_length_function: Option<SizingFunction<S>>,
+    progress_callback: Option<ProgressCallback<S>>,
     keep_constraint_edges: bool,
     exclude_outer_faces: bool,
+    conforming_delaunay: bool,
 }
 
 impl<S: SpadeNum + Float> Default for RefinementParameters<S> {
@@ -184,8 +295,12 @@ impl<S: SpadeNum + Float> Default for RefinementParameters<S> {
             angle_limit: AngleLimit::from_radius_to_shortest_edge_ratio(1.0),
             min_area: None,
             max_area: None,
+            max_area_function: None,
+            max_edge_length_function: None,
+            progress_callback: None,
             exclude_outer_faces: false,
             keep_constraint_edges: false,
+            conforming_delaunay: false,
         }
     }
 }
@@ -198,6 +313,9 @@ impl<S: SpadeNum + Float> RefinementParameters<S> {
     /// * `keep_constraint_edges`: disabled
     /// * `min_required_area`: disabled - no lower area limit is used
     /// * `max_allowed_area`: disabled - no upper area limit is used
+    /// * `max_allowed_area_function` and `max_edge_length_function`: disabled
+    /// * `progress_callback`: disabled
+    /// * `conforming_delaunay`: disabled
     /// * `angle_limit`: 30 degrees by default.
     /// * `num_additional_vertices`: 10 times the number of vertices in the triangulation
     pub fn new() -> Self {
@@ -265,6 +383,69 @@ impl<S: SpadeNum + Float> RefinementParameters<S> {
         self
     }
 
+    /// Specifies an upper bound for triangle areas that depends on the triangle's location.
+    ///
+    /// This allows to create *graded meshes*, e.g. meshes that are finely subdivided near their
+    /// boundary and coarse in their center. The function is evaluated at the center of each
+    /// triangle. Any triangle whose area is larger than the returned value will be split, just
+    /// as with [with_max_allowed_area](Self::with_max_allowed_area).
+    ///
+    /// The function should only return positive values and should not change too abruptly between
+    /// nearby positions. Use [with_max_additional_vertices](Self::with_max_additional_vertices) to
+    /// limit the number of inserted vertices if very small values can be returned.
+    ///
+    /// # Example
+    /// ```
+    /// use spade::{ConstrainedDelaunayTriangulation, Point2, RefinementParameters, Triangulation};
+    /// # fn main() -> Result<(), spade::InsertionError> {
+    /// let mut cdt = ConstrainedDelaunayTriangulation::<Point2<f64>>::new();
+    /// cdt.add_constraint_edges(
+    ///     [
+    ///         Point2::new(0.0, 0.0),
+    ///         Point2::new(10.0, 0.0),
+    ///         Point2::new(10.0, 10.0),
+    ///         Point2::new(0.0, 10.0),
+    ///     ],
+    ///     true,
+    /// )?;
+    ///
+    /// // Small triangles near x = 0, larger triangles near x = 10
+    /// let params = RefinementParameters::new()
+    ///     .with_max_allowed_area_function(|position: Point2<f64>| 0.1 + position.x * 0.2)
+    ///     .with_max_additional_vertices(10000);
+    /// let result = cdt.refine(params);
+    ///
+    /// assert!(result.refinement_complete);
+    /// for face in cdt.inner_faces() {
+    ///     assert!(face.area() <= 0.1 + face.center().x * 0.2);
+    /// }
+    /// # Ok(()) }
+    /// ```
+    pub fn with_max_allowed_area_function<F>(mut self, max_area: F) -> Self
+    where
+        F: Fn(Point2<S>) -> S + Send + Sync + 'static,
+    {
+        self.max_area_function = Some(UserFunction(Arc::new(max_area)));
+        self
+    }
+
+    /// Specifies an upper bound for edge lengths that depends on the location.
+    ///
+    /// The function is evaluated at the center of each triangle. Any triangle with an edge longer
+    /// than the returned value will be split. Similar to
+    /// [with_max_allowed_area_function](Self::with_max_allowed_area_function), this can be used
+    /// to create graded meshes.
+    ///
+    /// The function should only return positive values and should not change too abruptly between
+    /// nearby positions.
+    pub fn with_max_edge_length_function<F>(mut self, max_edge_length: F) -> Self
+    where
+        F: Fn(Point2<S>) -> S + Send + Sync + 'static,
+    {
+        self.max_edge_length_function = Some(UserFunction(Arc::new(max_edge_length)));
+        self
+    }
+
     /// Specifies how many additional vertices may be inserted during Delaunay refinement.
     ///
     /// Refinement may, in some cases, fail to terminate if the angle limit is set too high
@@ -279,6 +460,49 @@ impl<S: SpadeNum + Float> RefinementParameters<S> {
         self
     }
 
+    /// Specifies a callback that is invoked after every refinement step.
+    ///
+    /// The callback is called once for every inserted steiner point and once for every split of an
+    /// encroached segment, see [RefinementProgress]. Returning [ControlFlow::Break] stops the
+    /// refinement immediately. The triangulation will remain valid but may not be fully refined,
+    /// see [RefinementResult::cancelled].
+    ///
+    /// This can be used to report progress or to cancel a long running refinement from another thread.
+    ///
+    /// # Example
+    /// ```
+    /// use core::ops::ControlFlow;
+    /// use std::sync::atomic::{AtomicBool, Ordering};
+    /// use std::sync::Arc;
+    /// use spade::{ConstrainedDelaunayTriangulation, Point2, RefinementParameters};
+    ///
+    /// fn refine_cancellable(
+    ///     cdt: &mut ConstrainedDelaunayTriangulation<Point2<f64>>,
+    ///     cancel: Arc<AtomicBool>,
+    /// ) -> bool {
+    ///     let params = RefinementParameters::new().with_progress_callback(move |progress| {
+    ///         println!(
+    ///             "Inserted {} of at most {} vertices",
+    ///             progress.num_additional_vertices, progress.max_additional_vertices
+    ///         );
+    ///         if cancel.load(Ordering::Relaxed) {
+    ///             ControlFlow::Break(())
+    ///         } else {
+    ///             ControlFlow::Continue(())
+    ///         }
+    ///     });
+    ///
+    ///     !cdt.refine(params).cancelled
+    /// }
+    /// ```
+    pub fn with_progress_callback<C>(mut self, callback: C) -> Self
+    where
+        C: Fn(RefinementProgress<S>) -> ControlFlow<()> + Send + Sync + 'static,
+    {
+        self.progress_callback = Some(UserFunction(Arc::new(callback)));
+        self
+    }
+
     /// Prevents constraint edges from being split during refinement.
     ///
     /// By default, constraint edges may be split in order to restore the triangulation's Delaunay property.
@@ -292,6 +516,42 @@ impl<S: SpadeNum + Float> RefinementParameters<S> {
         self
     }
 
+    /// Only splits constraint edges until the triangulation becomes a *conforming Delaunay triangulation*.
+    ///
+    /// A CDT will, in general, not fulfill the Delaunay property as constraint edges cannot be flipped.
+    /// With this option, the refinement will split any constraint edge whose diametral circle contains
+    /// an adjacent vertex. Afterwards, every face's circumcircle will not contain any other vertex while
+    /// the shape outlined by all constraint edges remains the same. Such meshes are required, for example,
+    /// by some Voronoi based finite volume schemes.
+    ///
+    /// No other quality improvements are performed: The [angle limit](Self::with_angle_limit), any area or
+    /// edge length limits and any predicate passed to
+    /// [refine_with_predicate](ConstrainedDelaunayTriangulation::refine_with_predicate) are ignored. Only
+    /// constraint edges will be split, [keep_constraint_edges](Self::keep_constraint_edges) has no effect.
+    ///
+    /// Faces excluded by [exclude_outer_faces](Self::exclude_outer_faces) are not guaranteed to be Delaunay.
+    ///
+    /// # Example
+    /// ```
+    /// use spade::{ConstrainedDelaunayTriangulation, Point2, RefinementParameters, Triangulation};
+    /// # fn main() -> Result<(), spade::InsertionError> {
+    /// let mut cdt = ConstrainedDelaunayTriangulation::<Point2<f64>>::new();
+    /// cdt.insert(Point2::new(0.0, 1.0))?;
+    /// cdt.insert(Point2::new(0.0, -1.0))?;
+    /// cdt.add_constraint_edge(Point2::new(-10.0, 0.0), Point2::new(10.0, 0.0))?;
+    ///
+    /// let result = cdt.refine(RefinementParameters::new().conforming_delaunay());
+    ///
+    /// assert!(result.refinement_complete);
+    /// // The constraint edge has been split - its sub edges are still constraint edges.
+    /// assert!(cdt.num_constraints() > 1);
+    /// # Ok(()) }
+    /// ```
+    pub fn conforming_delaunay(mut self) -> Self {
+        self.conforming_delaunay = true;
+        self
+    }
+
     /// Allows to exclude outer faces from the refinement process.
     ///
     /// This is useful if the constraint edges form a *closed shape* with a clearly defined inner and outer part.
@@ -300,6 +560,7 @@ impl<S: SpadeNum + Float> RefinementParameters<S> {
     ///
     /// Any holes in the triangulation will also be excluded. More specifically, any point with an odd winding number
     /// is considered to be inner (see e.g. [Wikipedia](https://en.wikipedia.org/wiki/Point_in_polygon#Winding_number_algorithm)).
+    /// The same classification is returned by [ConstrainedDelaunayTriangulation::classify_inner_faces].
     ///
     /// Note that excluded faces may still be subdivided if a neighboring edge needs to be split. However, they will never be the
     /// *cause* for a subdivision - their angle and area is ignored.
@@ -320,6 +581,26 @@ impl<S: SpadeNum + Float> RefinementParameters<S> {
         self
     }
 
+    fn notify_progress(
+        &self,
+        event: RefinementEvent,
+        new_vertex: FixedVertexHandle,
+        position: Point2<S>,
+        num_additional_vertices: usize,
+        max_additional_vertices: usize,
+    ) -> ControlFlow<()> {
+        match &self.progress_callback {
+            Some(callback) => (callback.0)(RefinementProgress {
+                event,
+                new_vertex,
+                position,
+                num_additional_vertices,
+                max_additional_vertices,
+            }),
+            None => ControlFlow::Continue(()),
+        }
+    }
+
     fn get_refinement_hint<V, DE, UE, F>(
         &self,
         face: FaceHandle<InnerTag, V, DE, UE, F>,
@@ -333,6 +614,23 @@ impl<S: SpadeNum + Float> RefinementParameters<S> {
             }
         }
 
+        if let Some(max_area_function) = &self.max_area_function {
+            if face.area() > (max_area_function.0)(face.center()) {
+                return RefinementHint::MustRefine;
+            }
+        }
+
+        if let Some(max_edge_length_function) = &self.max_edge_length_function {
+            let max_length = (max_edge_length_function.0)(face.center());
+            let is_too_long = face
+                .adjacent_edges()
+                .iter()
+                .any(|edge| edge.length_2() > max_length * max_length);
+            if is_too_long {
+                return RefinementHint::MustRefine;
+            }
+        }
+
         if let Some(min_area) = self.min_area {
             if face.area() < min_area {
                 return RefinementHint::Ignore;
@@ -352,13 +650,14 @@ impl<S: SpadeNum + Float> RefinementParameters<S> {
     }
 }
 
-impl<V, DE, UE, F, L> ConstrainedDelaunayTriangulation<V, DE, UE, F, L>
+impl<V, DE, UE, F, L, C> ConstrainedDelaunayTriangulation<V, DE, UE, F, L, C>
 where
     V: HasPosition + From<Point2<<V as HasPosition>::Scalar>>,
     DE: Default,
     UE: Default,
     F: Default,
     L: HintGenerator<<V as HasPosition>::Scalar>,
+    C: Default + Clone,
     <V as HasPosition>::Scalar: Float,
 {
     /// Refines a triangulation by inserting additional points to improve the quality of its mesh.
@@ -460,6 +759,71 @@ where
     #[doc(alias = "Refinement")]
     #[doc(alias = "Delaunay Refinement")]
     pub fn refine(&mut self, parameters: RefinementParameters<V::Scalar>) -> RefinementResult {
+        self.refine_with_predicate(parameters, |_| RefinementHint::Ignore)
+    }
+
+    /// Refines a triangulation with an additional, user defined refinement criterion.
+    ///
+    /// This works like [refine](Self::refine) but calls `predicate` for every face that is checked
+    /// for its quality. The face will be refined according to the most urgent of the returned
+    /// [RefinementHint] and the hint derived from the [RefinementParameters]. Use an
+    /// [angle limit](RefinementParameters::with_angle_limit) of zero degrees to refine only the faces
+    /// selected by `predicate`.
+    ///
+    /// This can be used for adaptive remeshing, e.g. to refine faces marked by a previous simulation
+    /// step or faces across which an interpolated value changes too much. Faces created during
+    /// refinement will contain `F::default()` as their face data.
+    ///
+    /// Excluded faces (see [RefinementParameters::exclude_outer_faces]) are never passed to `predicate`.
+    /// Consider using [RefinementParameters::with_max_additional_vertices] if `predicate` may keep
+    /// demanding refinement for ever smaller faces.
+    ///
+    /// # Example
+    /// ```
+    /// use spade::{
+    ///     AngleLimit, ConstrainedDelaunayTriangulation, Point2, RefinementHint, RefinementParameters,
+    ///     Triangulation,
+    /// };
+    /// # fn main() -> Result<(), spade::InsertionError> {
+    /// let mut cdt = ConstrainedDelaunayTriangulation::<Point2<f64>>::new();
+    /// cdt.add_constraint_edges(
+    ///     [
+    ///         Point2::new(0.0, 0.0),
+    ///         Point2::new(10.0, 0.0),
+    ///         Point2::new(10.0, 10.0),
+    ///         Point2::new(0.0, 10.0),
+    ///     ],
+    ///     true,
+    /// )?;
+    ///
+    /// // Only refine faces adjacent to the origin
+    /// let params = RefinementParameters::new()
+    ///     .with_angle_limit(AngleLimit::from_deg(0.0))
+    ///     .with_max_additional_vertices(1000);
+    /// let result = cdt.refine_with_predicate(params, |face| {
+    ///     let touches_origin = face
+    ///         .positions()
+    ///         .iter()
+    ///         .any(|position| position.x == 0.0 && position.y == 0.0);
+    ///     if touches_origin && face.area() > 0.1 {
+    ///         RefinementHint::MustRefine
+    ///     } else {
+    ///         RefinementHint::Ignore
+    ///     }
+    /// });
+    ///
+    /// assert!(result.refinement_complete);
+    /// assert!(cdt.num_vertices() > 4);
+    /// # Ok(()) }
+    /// ```
+    pub fn refine_with_predicate<P>(
+        &mut self,
+        parameters: RefinementParameters<V::Scalar>,
+        mut predicate: P,
+    ) -> RefinementResult
+    where
+        P: FnMut(FaceHandle<InnerTag, V, DE, CdtEdge<UE, C>, F>) -> RefinementHint,
+    {
         use PositionInTriangulation::*;
 
         let mut excluded_faces = if parameters.exclude_outer_faces {
@@ -483,7 +847,9 @@ where
         encroached_segment_candidates.extend(
             self.undirected_edges()
                 .filter(|edge| {
-                    if parameters.keep_constraint_edges {
+                    if parameters.conforming_delaunay {
+                        edge.is_constraint_edge()
+                    } else if parameters.keep_constraint_edges {
                         edge.is_part_of_convex_hull()
                     } else {
                         Self::is_fixed_edge(*edge)
@@ -503,6 +869,18 @@ where
             usize::saturating_add(num_initial_vertices, num_additional_vertices);
 
         let mut refinement_complete = true;
+        let mut cancelled = false;
+
+        // Reports a newly inserted vertex to the progress callback
+        let notify_progress = |cdt: &Self, event, new_vertex: FixedVertexHandle| {
+            parameters.notify_progress(
+                event,
+                new_vertex,
+                cdt.vertex(new_vertex).position(),
+                cdt.num_vertices() - num_initial_vertices,
+                num_additional_vertices,
+            )
+        };
 
         // Main loop of the algorithm
         //
@@ -527,7 +905,7 @@ where
         //    restores the Delaunay property as quickly as possible.
         //  - Third, search for skinny triangles. Attempt to insert a new vertex at the triangles circumcenter. If inserting
         //    such a vertex would encroach any fixed edge, add the encroached edge to the forcibly split segments buffer
-        //    and revisit the face later.
+        //    and revisit the face later. This step is skipped if only a conforming Delaunay triangulation is required.
         //
         // See method `resolve_encroachment` for more details on how step 1 and 2 manage to split edges in order to resolve
         // an encroachment.
@@ -539,18 +917,29 @@ where
 
             // Step 1: Check for forcibly splitted segments.
             if let Some(forcibly_split_segment) = forcibly_split_segments_buffer.pop() {
-                self.resolve_encroachment(
+                let new_vertex = self.resolve_encroachment(
                     &mut encroached_segment_candidates,
                     &mut skinny_triangle_candidates,
                     &mut constraint_edge_map,
                     forcibly_split_segment,
                     &mut excluded_faces,
                 );
+                if let Some(new_vertex) = new_vertex {
+                    if notify_progress(self, RefinementEvent::SegmentSplit, new_vertex).is_break() {
+                        cancelled = true;
+                        break;
+                    }
+                }
                 continue;
             }
 
             // Step 2: Check for encroached segments.
             if let Some(segment_candidate) = encroached_segment_candidates.pop_front() {
+                if parameters.conforming_delaunay && !self.is_constraint_edge(segment_candidate) {
+                    // Splitting edges of the convex hull is not required to restore the Delaunay property.
+                    continue;
+                }
+
                 // Check both adjacent faces of any candidate for encroachment.
                 for edge in segment_candidate.directed_edges() {
                     let edge = self.directed_edge(edge);
@@ -572,13 +961,20 @@ where
                             opposite_position,
                         ) {
                             // The edge is encroaching
-                            self.resolve_encroachment(
+                            let new_vertex = self.resolve_encroachment(
                                 &mut encroached_segment_candidates,
                                 &mut skinny_triangle_candidates,
                                 &mut constraint_edge_map,
                                 segment_candidate,
                                 &mut excluded_faces,
                             );
+                            if let Some(new_vertex) = new_vertex {
+                                let event = RefinementEvent::SegmentSplit;
+                                if notify_progress(self, event, new_vertex).is_break() {
+                                    cancelled = true;
+                                    break 'main_loop;
+                                }
+                            }
                         }
                     }
                 }
@@ -586,6 +982,11 @@ where
                 continue;
             }
 
+            if parameters.conforming_delaunay {
+                // All constraint edges are free of encroachment, the triangulation is Delaunay.
+                break;
+            }
+
             // Step 3: Take the next skinny triangle candidate
             if let Some(face) = skinny_triangle_candidates.pop_front() {
                 if excluded_faces.contains(&face) {
@@ -596,7 +997,7 @@ where
 
                 let (shortest_edge, _) = face.shortest_edge();
 
-                let refinement_hint = parameters.get_refinement_hint(face);
+                let refinement_hint = parameters.get_refinement_hint(face).max(predicate(face));
 
                 if refinement_hint == RefinementHint::Ignore {
                     // Triangle is fine as is and can be skipped
@@ -729,6 +1130,12 @@ where
                             .out_edges()
                             .flat_map(|edge| edge.face().fix().as_inner()),
                     );
+
+                    let event = RefinementEvent::SteinerPointInserted;
+                    if notify_progress(self, event, new_vertex).is_break() {
+                        cancelled = true;
+                        break;
+                    }
                 } else if !forcibly_split_segments_buffer.is_empty() {
                     // Revisit this face later. Since the encroached edge will have been split in the next iteration,
                     // inserting the circumcenter might succeed this time around.
@@ -742,11 +1149,64 @@ where
 
         RefinementResult {
             excluded_faces: excluded_faces.iter().copied().collect(),
-            refinement_complete,
+            refinement_complete: refinement_complete && !cancelled,
+            cancelled,
+            inserted_vertices: (num_initial_vertices..self.num_vertices())
+                .map(FixedVertexHandle::new)
+                .collect(),
+            split_constraints: self.collect_split_constraints(&constraint_edge_map),
+        }
+    }
+
+    fn collect_split_constraints(
+        &self,
+        constraint_edge_map: &HashMap<FixedVertexHandle, [FixedVertexHandle; 2]>,
+    ) -> Vec<ConstraintSplit> {
+        let mut original_edges = constraint_edge_map
+            .values()
+            .copied()
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect::<Vec<_>>();
+        original_edges.sort();
+
+        let mut result = Vec::new();
+        for [v0, v1] in original_edges {
+            // Walk along the sub edges from v0 towards v1. Every inner vertex of this chain must be a steiner point
+            // that was created by splitting the original edge. Edges of the convex hull are split in the same way
+            // but are not reported as they are no constraint edges.
+            let mut sub_edges = Vec::new();
+            let mut previous = None;
+            let mut current = self.vertex(v0);
+            while current.fix() != v1 {
+                let next = current.out_edges().find(|edge| {
+                    let to = edge.to().fix();
+                    edge.is_constraint_edge()
+                        && Some(to) != previous
+                        && (to == v1 || constraint_edge_map.get(&to) == Some(&[v0, v1]))
+                });
+
+                if let Some(next) = next {
+                    sub_edges.push(next.fix());
+                    previous = Some(current.fix());
+                    current = next.to();
+                } else {
+                    sub_edges.clear();
+                    break;
+                }
+            }
+
+            if !sub_edges.is_empty() {
+                result.push(ConstraintSplit {
+                    original_vertices: [v0, v1],
+                    sub_edges,
+                });
+            }
         }
+        result
     }
 
-    fn is_fixed_edge(edge: UndirectedEdgeHandle<V, DE, CdtEdge<UE>, F>) -> bool {
+    fn is_fixed_edge(edge: UndirectedEdgeHandle<V, DE, CdtEdge<UE, C>, F>) -> bool {
         edge.is_constraint_edge() || edge.is_part_of_convex_hull()
     }
 
@@ -757,7 +1217,7 @@ where
         constraint_edge_map: &mut HashMap<FixedVertexHandle, [FixedVertexHandle; 2]>,
         encroached_edge: FixedUndirectedEdgeHandle,
         excluded_faces: &mut HashSet<FixedFaceHandle<InnerTag>>,
-    ) {
+    ) -> Option<FixedVertexHandle> {
         // Resolves an encroachment by splitting the encroached edge. Since this reduces the diametral circle, this will
         // eventually get rid of the encroachment completely.
         //
@@ -814,7 +1274,7 @@ where
         let final_position = v0.position().mul(weight0).add(v1.position().mul(weight1));
 
         if !validate_constructed_vertex(final_position, segment) {
-            return;
+            return None;
         }
 
         let [is_left_side_excluded, is_right_side_excluded] =
@@ -878,6 +1338,8 @@ where
         // Update encroachment candidates - any of the resulting edges may still be in an encroaching state.
         encroached_segments_buffer.push_back(e1.as_undirected());
         encroached_segments_buffer.push_back(e2.as_undirected());
+
+        Some(new_vertex)
     }
 }
 
@@ -899,7 +1361,7 @@ where
 /// v0 -v-> v1
 ///   \ |  /
 ///     v3
-fn validate_constructed_vertex<V, DE, UE, F>(
+pub(crate) fn validate_constructed_vertex<V, DE, UE, F>(
     final_position: Point2<V::Scalar>,
     segment: DirectedEdgeHandle<V, DE, UE, F>,
 ) -> bool
@@ -943,11 +1405,16 @@ fn nearest_power_of_two<S: Float + SpadeNum>(input: S) -> S {
     input.log2().round().exp2()
 }
 
-fn calculate_outer_faces<V: HasPosition, DE: Default, UE: Default, F: Default, L>(
-    triangulation: &ConstrainedDelaunayTriangulation<V, DE, UE, F, L>,
+pub(crate) fn calculate_outer_faces<V, DE, UE, F, L, C>(
+    triangulation: &ConstrainedDelaunayTriangulation<V, DE, UE, F, L, C>,
 ) -> HashSet<FixedFaceHandle<InnerTag>>
 where
+    V: HasPosition,
+    DE: Default,
+    UE: Default,
+    F: Default,
     L: HintGenerator<<V as HasPosition>::Scalar>,
+    C: Default + Clone,
 {
     if triangulation.all_vertices_on_line() {
         return HashSet::new();
@@ -1001,12 +1468,12 @@ where
 
 #[cfg(test)]
 mod test {
-    use super::HashSet;
+    use super::{math, HashSet};
 
     use crate::{
         test_utilities::{random_points_with_seed, SEED},
-        AngleLimit, ConstrainedDelaunayTriangulation, InsertionError, Point2, RefinementParameters,
-        Triangulation as _,
+        AngleLimit, ConstrainedDelaunayTriangulation, InsertionError, Point2, RefinementEvent,
+        RefinementHint, RefinementParameters, Triangulation as _,
     };
 
     pub type Cdt = ConstrainedDelaunayTriangulation<Point2<f64>>;
@@ -1204,6 +1671,278 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_sizing_functions() -> Result<(), InsertionError> {
+        let boundary = [
+            Point2::new(0.0, 0.0),
+            Point2::new(10.0, 0.0),
+            Point2::new(10.0, 10.0),
+            Point2::new(0.0, 10.0),
+        ];
+        let max_edge_length = |position: Point2<f64>| 0.2 + position.x * 0.2;
+
+        let mut cdt = Cdt::new();
+        cdt.add_constraint_edges(boundary, true)?;
+        let result = cdt.refine(
+            RefinementParameters::new()
+                .with_max_edge_length_function(max_edge_length)
+                .with_max_additional_vertices(10000),
+        );
+
+        assert!(result.refinement_complete);
+        let mut num_left_vertices = 0;
+        let mut num_right_vertices = 0;
+        for face in cdt.inner_faces() {
+            for edge in face.adjacent_edges() {
+                assert!(edge.length_2().sqrt() <= max_edge_length(face.center()));
+            }
+        }
+        for vertex in cdt.vertices() {
+            if vertex.position().x < 5.0 {
+                num_left_vertices += 1;
+            } else {
+                num_right_vertices += 1;
+            }
+        }
+        assert!(num_left_vertices > 2 * num_right_vertices);
+
+        // A constant function behaves like a global area limit
+        let mut cdt = Cdt::new();
+        cdt.add_constraint_edges(boundary, true)?;
+        let params = RefinementParameters::new()
+            .with_max_allowed_area_function(|_| 2.0)
+            .with_max_additional_vertices(1000);
+        assert_eq!(params, params.clone());
+        assert_ne!(
+            params,
+            RefinementParameters::new()
+                .with_max_allowed_area_function(|_| 2.0)
+                .with_max_additional_vertices(1000)
+        );
+        cdt.refine(params);
+
+        let mut expected = Cdt::new();
+        expected.add_constraint_edges(boundary, true)?;
+        expected.refine(
+            RefinementParameters::new()
+                .with_max_allowed_area(2.0)
+                .with_max_additional_vertices(1000),
+        );
+        assert_eq!(cdt.num_vertices(), expected.num_vertices());
+        Ok(())
+    }
+
+    #[test]
+    fn test_refine_with_predicate() -> Result<(), InsertionError> {
+        let boundary = [
+            Point2::new(0.0, 0.0),
+            Point2::new(10.0, 0.0),
+            Point2::new(10.0, 10.0),
+            Point2::new(0.0, 10.0),
+        ];
+
+        // Mark a single face and refine only the area covered by it
+        let mut cdt = ConstrainedDelaunayTriangulation::<Point2<f64>, (), (), bool>::new();
+        cdt.add_constraint_edges(boundary, true)?;
+        let marked_face = cdt.fixed_inner_faces().next().unwrap();
+        *cdt.face_data_mut(marked_face) = true;
+        let marked_vertices = cdt.face(marked_face).positions();
+
+        let params = RefinementParameters::new().with_angle_limit(AngleLimit::from_deg(0.0));
+        let result = cdt.refine_with_predicate(params, |face| {
+            let [v0, v1, v2] = marked_vertices;
+            let is_inside_marked_face = math::is_ordered_ccw(v0, v1, face.center())
+                && math::is_ordered_ccw(v1, v2, face.center())
+                && math::is_ordered_ccw(v2, v0, face.center());
+            if (*face.data() || is_inside_marked_face) && face.area() > 5.0 {
+                RefinementHint::MustRefine
+            } else {
+                RefinementHint::Ignore
+            }
+        });
+
+        assert!(result.refinement_complete);
+        assert!(cdt.num_vertices() > 4);
+        for face in cdt.inner_faces() {
+            if !*face.data() {
+                continue;
+            }
+            // Only the original face should still be marked and it must have been split
+            assert_eq!(face.fix(), marked_face);
+            assert!(face.area() <= 5.0);
+        }
+
+        // A predicate that never refines doesn't change the result
+        let mut cdt = Cdt::new();
+        cdt.add_constraint_edges(boundary, true)?;
+        cdt.refine_with_predicate(RefinementParameters::new(), |_| RefinementHint::Ignore);
+
+        let mut expected = Cdt::new();
+        expected.add_constraint_edges(boundary, true)?;
+        expected.refine(RefinementParameters::new());
+        assert_eq!(cdt.num_vertices(), expected.num_vertices());
+
+        assert!(RefinementHint::Ignore < RefinementHint::ShouldRefine);
+        assert!(RefinementHint::ShouldRefine < RefinementHint::MustRefine);
+        Ok(())
+    }
+
+    #[test]
+    fn test_progress_callback() -> Result<(), InsertionError> {
+        use alloc::sync::Arc;
+        use core::ops::ControlFlow;
+        use core::sync::atomic::{AtomicUsize, Ordering};
+
+        let vertices = random_points_with_seed(50, SEED);
+
+        // Count all events of a complete refinement
+        let mut cdt = Cdt::bulk_load(vertices.clone())?;
+        let num_events = Arc::new(AtomicUsize::new(0));
+        let num_split_events = Arc::new(AtomicUsize::new(0));
+        let params = RefinementParameters::new().with_progress_callback({
+            let num_events = num_events.clone();
+            let num_split_events = num_split_events.clone();
+            move |progress| {
+                let previous = num_events.fetch_add(1, Ordering::Relaxed);
+                assert_eq!(progress.num_additional_vertices, previous + 1);
+                assert_eq!(progress.max_additional_vertices, 500);
+                if progress.event == RefinementEvent::SegmentSplit {
+                    num_split_events.fetch_add(1, Ordering::Relaxed);
+                }
+                ControlFlow::Continue(())
+            }
+        });
+        let result = cdt.refine(params);
+
+        assert!(result.refinement_complete);
+        assert!(!result.cancelled);
+        assert_eq!(
+            num_events.load(Ordering::Relaxed),
+            cdt.num_vertices() - vertices.len()
+        );
+        assert!(num_split_events.load(Ordering::Relaxed) > 0);
+
+        // Cancel after a few insertions
+        let mut cdt = Cdt::bulk_load(vertices.clone())?;
+        let params = RefinementParameters::new().with_progress_callback(|progress| {
+            if progress.num_additional_vertices == 5 {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        });
+        let result = cdt.refine(params);
+
+        assert!(result.cancelled);
+        assert!(!result.refinement_complete);
+        assert_eq!(cdt.num_vertices(), vertices.len() + 5);
+        cdt.cdt_sanity_check();
+        Ok(())
+    }
+
+    #[test]
+    fn test_inserted_vertices_and_split_constraints() -> Result<(), InsertionError> {
+        let mut cdt = Cdt::bulk_load(random_points_with_seed(30, SEED))?;
+        let v0 = cdt.insert(Point2::new(-0.9, -0.1))?;
+        let v1 = cdt.insert(Point2::new(0.9, 0.1))?;
+        cdt.add_constraint(v0, v1);
+        let num_initial_vertices = cdt.num_vertices();
+
+        let result = cdt.refine(RefinementParameters::new().with_max_allowed_area(0.01));
+
+        assert!(result.refinement_complete);
+        assert_eq!(
+            result.inserted_vertices.len(),
+            cdt.num_vertices() - num_initial_vertices
+        );
+        for vertex in &result.inserted_vertices {
+            assert!(vertex.index() >= num_initial_vertices);
+        }
+
+        assert!(!result.split_constraints.is_empty());
+        let mut num_sub_edges = 0;
+        for split in &result.split_constraints {
+            let [from, to] = split.original_vertices;
+            assert!(from.index() < num_initial_vertices);
+            assert!(to.index() < num_initial_vertices);
+            assert!(split.sub_edges.len() >= 2);
+
+            let mut current = from;
+            for edge in &split.sub_edges {
+                let edge = cdt.directed_edge(*edge);
+                assert!(edge.is_constraint_edge());
+                assert_eq!(edge.from().fix(), current);
+                current = edge.to().fix();
+            }
+            assert_eq!(current, to);
+            num_sub_edges += split.sub_edges.len();
+        }
+
+        // The only constraint edge has been replaced by its sub edges
+        assert_eq!(result.split_constraints.len(), 1);
+        assert_eq!(cdt.num_constraints(), num_sub_edges);
+
+        // Nothing is split if constraint edges are kept
+        let mut cdt = Cdt::bulk_load(random_points_with_seed(30, SEED))?;
+        let v0 = cdt.insert(Point2::new(-0.9, -0.1))?;
+        let v1 = cdt.insert(Point2::new(0.9, 0.1))?;
+        cdt.add_constraint(v0, v1);
+        let result = cdt.refine(RefinementParameters::new().keep_constraint_edges());
+        assert!(result.split_constraints.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_conforming_delaunay() -> Result<(), InsertionError> {
+        let mut cdt = Cdt::bulk_load(random_points_with_seed(100, SEED))?;
+        let constraints = [
+            [Point2::new(-0.9, -0.5), Point2::new(0.9, -0.95)],
+            [Point2::new(-0.9, 0.9), Point2::new(-0.1, 0.05)],
+            [Point2::new(-0.1, 0.05), Point2::new(0.8, -0.9)],
+        ];
+        for [from, to] in constraints {
+            cdt.add_constraint_edge(from, to)?;
+        }
+        let num_initial_vertices = cdt.num_vertices();
+
+        let result = cdt.refine(
+            RefinementParameters::new()
+                .conforming_delaunay()
+                .with_max_allowed_area(0.0001)
+                .with_max_additional_vertices(10000),
+        );
+        assert!(result.refinement_complete);
+        assert!(cdt.num_vertices() > num_initial_vertices);
+        cdt.cdt_sanity_check();
+
+        // Only constraint edges have been split
+        for vertex in &result.inserted_vertices {
+            let num_constraint_edges = cdt
+                .vertex(*vertex)
+                .out_edges()
+                .filter(|edge| edge.is_constraint_edge())
+                .count();
+            assert_eq!(num_constraint_edges, 2);
+        }
+
+        // Every edge must be locally Delaunay
+        for edge in cdt.directed_edges() {
+            if edge.is_outer_edge() || edge.rev().is_outer_edge() {
+                continue;
+            }
+            let [from, to] = edge.positions();
+            let opposite = edge.opposite_position().unwrap();
+            let other_opposite = edge.rev().opposite_position().unwrap();
+            assert!(!math::contained_in_circumference(
+                from,
+                to,
+                opposite,
+                other_opposite
+            ));
+        }
+        Ok(())
+    }
+
     #[test]
     fn test_failing_refinement() -> Result<(), InsertionError> {
         // f32 is important - only then, rounding errors will lead to violating the ccw property when
@@ -1230,7 +1969,8 @@ mod test {
         }
 
         cdt.refine(Default::default());
-        cdt.cdt_sanity_check();
+        // Rounded split positions may violate the Delaunay property
+        cdt.cdt_sanity_check_with_params(false);
 
         Ok(())
     }
@@ -0,0 +1,213 @@
+use alloc::vec::Vec;
+
+use num_traits::{Float, One, Zero};
+
+use crate::{
+    ConstrainedDelaunayTriangulation, HasPosition, HasPositionMut, HintGenerator, Point2,
+    QualityReport, RefinementResult, Triangulation,
+};
+
+use super::{math, quality_report, FixedVertexHandle};
+
+/// Contains details about the outcome of a smoothing operation.
+///
+/// *See [ConstrainedDelaunayTriangulation::smooth_steiner_points]*
+#[derive(Debug, Clone, PartialEq)]
+pub struct SmoothingResult<S> {
+    /// Quality report of all non-excluded inner faces before smoothing.
+    pub before: QualityReport<S>,
+
+    /// Quality report of all non-excluded inner faces after smoothing.
+    pub after: QualityReport<S>,
+
+    /// The total number of vertex moves performed over all iterations.
+    pub num_vertex_moves: usize,
+}
+
+impl<V, DE, UE, F, L, C> ConstrainedDelaunayTriangulation<V, DE, UE, F, L, C>
+where
+    V: HasPositionMut + From<Point2<<V as HasPosition>::Scalar>>,
+    DE: Default,
+    UE: Default,
+    F: Default,
+    L: HintGenerator<<V as HasPosition>::Scalar>,
+    C: Default + Clone,
+    <V as HasPosition>::Scalar: Float,
+{
+    /// Improves the mesh quality after a refinement by smoothing its steiner points.
+    ///
+    /// Refinement may leave poorly shaped triangles behind, especially close to small input angles.
+    /// This method performs *Laplacian smoothing*: Every steiner point listed in
+    /// [RefinementResult::inserted_vertices] is moved towards the average position of its neighbors.
+    /// This is repeated `iterations` times.
+    ///
+    /// A vertex is only moved if this increases the smallest angle of its adjacent triangles
+    /// ("smart" Laplacian smoothing). Input vertices and any steiner point lying on a constraint edge
+    /// or on the convex hull are never moved - the shape outlined by constraint edges remains the
+    /// same. The triangulation's Delaunay property is restored after every move.
+    ///
+    /// The returned [SmoothingResult] contains a [QualityReport] of all inner faces before and after
+    /// smoothing. Faces listed in [RefinementResult::excluded_faces] are not included.
+    ///
+    /// `refinement_result` must be the result of the last [refine](Self::refine) call. The triangulation
+    /// must not have been modified since.
+    ///
+    /// # Example
+    /// ```
+    /// use spade::{ConstrainedDelaunayTriangulation, Point2, RefinementParameters, Triangulation};
+    /// # fn main() -> Result<(), spade::InsertionError> {
+    /// let mut cdt = ConstrainedDelaunayTriangulation::<Point2<f64>>::new();
+    /// cdt.add_constraint_edges(
+    ///     [
+    ///         Point2::new(0.0, 0.0),
+    ///         Point2::new(10.0, 0.0),
+    ///         Point2::new(0.0, 2.0),
+    ///     ],
+    ///     true,
+    /// )?;
+    ///
+    /// let refinement_result = cdt.refine(
+    ///     RefinementParameters::new()
+    ///         .exclude_outer_faces(true)
+    ///         .with_max_allowed_area(0.5),
+    /// );
+    /// let smoothing_result = cdt.smooth_steiner_points(&refinement_result, 5);
+    ///
+    /// assert!(smoothing_result.after.min_angle.min >= smoothing_result.before.min_angle.min);
+    /// # Ok(()) }
+    /// ```
+    pub fn smooth_steiner_points(
+        &mut self,
+        refinement_result: &RefinementResult,
+        iterations: usize,
+    ) -> SmoothingResult<V::Scalar> {
+        let excluded_faces = &refinement_result.excluded_faces;
+        let before = quality_report(self, excluded_faces);
+
+        let mut num_vertex_moves = 0;
+        for _ in 0..iterations {
+            let mut num_moves_in_iteration = 0;
+            for vertex in &refinement_result.inserted_vertices {
+                if self.smooth_vertex(*vertex) {
+                    num_moves_in_iteration += 1;
+                }
+            }
+
+            num_vertex_moves += num_moves_in_iteration;
+            if num_moves_in_iteration == 0 {
+                break;
+            }
+        }
+
+        SmoothingResult {
+            before,
+            after: quality_report(self, excluded_faces),
+            num_vertex_moves,
+        }
+    }
+
+    /// Moves a vertex to the average position of its neighbors.
+    ///
+    /// Returns `false` and does nothing if the vertex is fixed or if moving it would not improve
+    /// the smallest angle of its adjacent faces.
+    fn smooth_vertex(&mut self, vertex: FixedVertexHandle) -> bool {
+        let handle = self.vertex(vertex);
+        let position = handle.position();
+
+        let mut star_border = Vec::new();
+        for edge in handle.out_edges() {
+            if edge.is_outer_edge() || edge.is_constraint_edge() {
+                return false;
+            }
+            star_border.push(edge.next().positions());
+        }
+
+        if star_border.is_empty() {
+            return false;
+        }
+
+        let zero = V::Scalar::zero();
+        let sum = star_border
+            .iter()
+            .fold(Point2::new(zero, zero), |sum, [from, _]| sum.add(*from));
+        let new_position = sum.mul(V::Scalar::one() / (star_border.len() as f32).into());
+
+        let min_angle = |center: Point2<V::Scalar>| {
+            star_border
+                .iter()
+                .map(|[from, to]| {
+                    let [a0, a1, a2] = math::triangle_angles([center, *from, *to]);
+                    a0.min(a1).min(a2)
+                })
+                .fold(V::Scalar::infinity(), |a, b| a.min(b))
+        };
+
+        // The new position must lie strictly within the star. Otherwise, some adjacent triangle
+        // would be flipped over.
+        let is_within_star = star_border
+            .iter()
+            .all(|[from, to]| math::side_query(*from, *to, new_position).is_on_left_side());
+
+        if !is_within_star || min_angle(new_position) <= min_angle(position) {
+            return false;
+        }
+
+        self.move_vertex(vertex, new_position) == Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        ConstrainedDelaunayTriangulation, InsertionError, Point2, RefinementParameters,
+        Triangulation as _,
+    };
+
+    pub type Cdt = ConstrainedDelaunayTriangulation<Point2<f64>>;
+
+    #[test]
+    fn test_smooth_steiner_points() -> Result<(), InsertionError> {
+        let boundary = [
+            Point2::new(0.0, 0.0),
+            Point2::new(10.0, 0.0),
+            Point2::new(10.0, 10.0),
+            Point2::new(3.0, 0.5),
+            Point2::new(0.0, 10.0),
+        ];
+
+        let mut cdt = Cdt::new();
+        cdt.add_constraint_edges(boundary, true)?;
+        let num_constraints = cdt.num_constraints();
+
+        let refinement_result = cdt.refine(
+            RefinementParameters::new()
+                .exclude_outer_faces(true)
+                .with_max_allowed_area(0.5)
+                .with_max_additional_vertices(10000),
+        );
+        assert!(refinement_result.refinement_complete);
+        let num_split_constraints = cdt.num_constraints();
+        assert!(num_split_constraints >= num_constraints);
+
+        let input_positions = cdt
+            .vertices()
+            .take(boundary.len())
+            .map(|vertex| vertex.position())
+            .collect::<alloc::vec::Vec<_>>();
+
+        let result = cdt.smooth_steiner_points(&refinement_result, 10);
+
+        assert!(result.num_vertex_moves > 0);
+        assert!(result.after.min_angle.min >= result.before.min_angle.min);
+        assert!(result.after.min_angle.mean > result.before.min_angle.mean);
+        assert_eq!(result.before.num_faces, result.after.num_faces);
+
+        // Input vertices and constraint edges are kept intact
+        for (vertex, position) in cdt.vertices().zip(input_positions) {
+            assert_eq!(vertex.position(), position);
+        }
+        assert_eq!(cdt.num_constraints(), num_split_constraints);
+        cdt.cdt_sanity_check();
+        Ok(())
+    }
+}
@@ -7,7 +7,7 @@ use super::math;
 
 use crate::HintGenerator;
 use crate::Point2;
-use crate::{HasPosition, InsertionError, PositionInTriangulation, Triangulation};
+use crate::{HasPosition, HasPositionMut, InsertionError, PositionInTriangulation, Triangulation};
 
 use alloc::{collections::VecDeque, vec::Vec};
 
@@ -387,14 +387,19 @@ pub trait TriangulationExt: Triangulation {
                 // edge     |    \  |
                 //          |     \ |
                 //         v1-------v3
-                let v2 = edge.rev().opposite_position();
-                let v3 = edge.opposite_position();
+                let v2 = edge.rev().opposite_vertex();
+                let v3 = edge.opposite_vertex();
 
                 if let (Some(v2), Some(v3)) = (v2, v3) {
-                    let v0 = edge.from().position();
-                    let v1 = edge.to().position();
-                    debug_assert!(math::is_ordered_ccw(v2, v1, v0));
-                    let should_flip = math::contained_in_circumference(v2, v1, v0, v3);
+                    let v0 = edge.from();
+                    let v1 = edge.to();
+                    debug_assert!(math::is_ordered_ccw(
+                        v0.position(),
+                        v1.position(),
+                        v3.position()
+                    ));
+                    let should_flip =
+                        self.contained_in_circumference(v0.data(), v1.data(), v3.data(), v2.data());
                     result |= should_flip;
 
                     if should_flip {
@@ -613,6 +618,97 @@ pub trait TriangulationExt: Triangulation {
         removal_result.removed_vertex
     }
 
+    fn move_vertex_and_notify(
+        &mut self,
+        vertex: FixedVertexHandle,
+        new_position: Point2<<Self::Vertex as HasPosition>::Scalar>,
+    ) -> Result<bool, InsertionError>
+    where
+        Self::Vertex: HasPositionMut,
+    {
+        math::validate_coordinate(new_position.x)?;
+        math::validate_coordinate(new_position.y)?;
+
+        let old_position = self.vertex(vertex).position();
+        if old_position == new_position {
+            return Ok(true);
+        }
+
+        if !self.move_vertex_within_star(vertex, new_position) {
+            if self.locate_vertex(new_position).is_some() {
+                return Ok(false);
+            }
+            self.move_vertex_by_reinsertion(vertex, new_position);
+        }
+
+        self.hint_generator_mut()
+            .notify_vertex_moved(vertex, old_position, new_position);
+        Ok(true)
+    }
+
+    /// Moves a vertex to a new position that lies strictly within the vertex's star.
+    ///
+    /// The star of a vertex refers to the polygon formed by all of its adjacent faces. All of these
+    /// faces remain valid triangles as long as the new position can "see" all edges of the star's
+    /// border. Only a few local flips are required to restore the Delaunay property afterwards.
+    ///
+    /// Returns `false` and does nothing if the vertex cannot be moved this way. This is always the
+    /// case for vertices on the convex hull.
+    fn move_vertex_within_star(
+        &mut self,
+        vertex: FixedVertexHandle,
+        new_position: Point2<<Self::Vertex as HasPosition>::Scalar>,
+    ) -> bool
+    where
+        Self::Vertex: HasPositionMut,
+    {
+        if self.all_vertices_on_line() {
+            return false;
+        }
+
+        let mut edges_to_validate = Vec::new();
+        for edge in self.vertex(vertex).out_edges() {
+            if edge.is_outer_edge() {
+                return false;
+            }
+
+            let [from, to] = edge.next().positions();
+            if !math::side_query(from, to, new_position).is_on_left_side() {
+                return false;
+            }
+
+            edges_to_validate.push(edge.fix().as_undirected());
+            edges_to_validate.push(edge.next().fix().as_undirected());
+        }
+
+        self.s_mut()
+            .vertex_data_mut(vertex)
+            .set_position(new_position);
+        self.legalize_edges_after_removal(&mut edges_to_validate, |_| false);
+        true
+    }
+
+    /// Moves a vertex by removing it and inserting it again. All vertex handles remain valid.
+    ///
+    /// `new_position` must not be occupied by another vertex.
+    fn move_vertex_by_reinsertion(
+        &mut self,
+        vertex: FixedVertexHandle,
+        new_position: Point2<<Self::Vertex as HasPosition>::Scalar>,
+    ) where
+        Self::Vertex: HasPositionMut,
+    {
+        let mut data = self.remove_core(vertex).removed_vertex;
+        data.set_position(new_position);
+        let new_handle = self.insert_with_hint_option_impl(data, None);
+
+        // Removal has moved the last vertex into the removed vertex's slot while the new vertex
+        // has been appended. Swapping both restores the previous handles.
+        if new_handle != vertex {
+            dcel_operations::swap_vertices(self.s_mut(), vertex, new_handle);
+        }
+    }
+
     fn remove_core(&mut self, vertex_to_remove: FixedVertexHandle) -> RemovalResult<Self::Vertex> {
         if self.num_all_faces() <= 1 {
             return dcel_operations::remove_when_degenerate(self.s_mut(), vertex_to_remove);
@@ -749,18 +845,25 @@ pub trait TriangulationExt: Triangulation {
             let e2 = edge.prev();
             let e4 = edge.rev().prev();
 
-            let from = edge.from().position();
-            let to = edge.to().position();
-            let left = edge.opposite_position();
-            let right = edge.rev().opposite_position();
+            let from = edge.from();
+            let to = edge.to();
+            let left = edge.opposite_vertex();
+            let right = edge.rev().opposite_vertex();
 
             let should_flip = match (left, right) {
-                (Some(left), Some(right)) => {
-                    math::contained_in_circumference(from, to, left, right)
-                }
+                (Some(left), Some(right)) => self.contained_in_circumference(
+                    from.data(),
+                    to.data(),
+                    left.data(),
+                    right.data(),
+                ),
                 // Handle special cases when evaluating edges next to the convex hull
-                (None, Some(right)) => math::is_ordered_ccw(right, from, to),
-                (Some(left), None) => math::is_ordered_ccw(left, to, from),
+                (None, Some(right)) => {
+                    math::is_ordered_ccw(right.position(), from.position(), to.position())
+                }
+                (Some(left), None) => {
+                    math::is_ordered_ccw(left.position(), to.position(), from.position())
+                }
                 (None, None) => {
                     panic!("Unexpected geometry. This is a bug in spade.")
                 }
@@ -840,15 +943,13 @@ pub trait TriangulationExt: Triangulation {
             let rev = edge.rev();
 
             if let (Some(edge_opposite), Some(rev_opposite)) =
-                (edge.opposite_position(), rev.opposite_position())
+                (edge.opposite_vertex(), rev.opposite_vertex())
             {
-                let from = edge.from().position();
-                let to = edge.to().position();
-                assert!(!math::contained_in_circumference(
-                    from,
-                    to,
-                    edge_opposite,
-                    rev_opposite
+                assert!(!self.contained_in_circumference(
+                    edge.from().data(),
+                    edge.to().data(),
+                    edge_opposite.data(),
+                    rev_opposite.data(),
                 ))
             }
         }
@@ -1479,4 +1580,100 @@ mod test {
         triangulation.sanity_check();
         Ok(())
     }
+
+    fn check_positions(
+        triangulation: &DelaunayTriangulation<Point2<f64>>,
+        positions: &[Point2<f64>],
+    ) {
+        assert_eq!(triangulation.num_vertices(), positions.len());
+        for (index, position) in positions.iter().enumerate() {
+            let handle = FixedVertexHandle::new(index);
+            assert_eq!(triangulation.vertex(handle).position(), *position);
+            assert_eq!(
+                triangulation.locate_vertex(*position).unwrap().fix(),
+                handle
+            );
+        }
+    }
+
+    #[test]
+    fn test_move_vertex_small_distances() -> Result<(), InsertionError> {
+        let mut positions = random_points_with_seed(200, SEED);
+        let mut triangulation =
+            DelaunayTriangulation::<_>::bulk_load_stable(positions.clone()).unwrap();
+
+        let mut rng = rand::rngs::StdRng::from_seed(*SEED2);
+        let range = Uniform::new(-0.02, 0.02);
+        for _ in 0..5 {
+            for (index, position) in positions.iter_mut().enumerate() {
+                let new_position = Point2::new(
+                    position.x + range.sample(&mut rng),
+                    position.y + range.sample(&mut rng),
+                );
+                assert!(triangulation.move_vertex(FixedVertexHandle::new(index), new_position)?);
+                *position = new_position;
+            }
+            triangulation.sanity_check();
+            check_positions(&triangulation, &positions);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_move_vertex_large_distances() -> Result<(), InsertionError> {
+        let mut positions = random_points_with_seed(100, SEED);
+        let mut triangulation =
+            DelaunayTriangulation::<_>::bulk_load_stable(positions.clone()).unwrap();
+
+        let new_positions = random_points_in_range(3.0, 100, SEED2);
+        for (index, new_position) in new_positions.into_iter().enumerate() {
+            assert!(triangulation.move_vertex(FixedVertexHandle::new(index), new_position)?);
+            positions[index] = new_position;
+            triangulation.sanity_check();
+        }
+        check_positions(&triangulation, &positions);
+        Ok(())
+    }
+
+    #[test]
+    fn test_move_vertex_special_cases() -> Result<(), InsertionError> {
+        let mut positions = vec![
+            Point2::new(0.0, 0.0),
+            Point2::new(1.0, 0.0),
+            Point2::new(2.0, 0.0),
+        ];
+        let mut triangulation =
+            DelaunayTriangulation::<_>::bulk_load_stable(positions.clone()).unwrap();
+        let v0 = FixedVertexHandle::new(0);
+        let v1 = FixedVertexHandle::new(1);
+
+        // Other vertex at the target position
+        assert!(!triangulation.move_vertex(v0, Point2::new(1.0, 0.0))?);
+        assert!(triangulation
+            .move_vertex(v0, Point2::new(f64::NAN, 0.0))
+            .is_err());
+        check_positions(&triangulation, &positions);
+
+        // Moving along the line and off the line
+        for new_position in [
+            Point2::new(3.0, 0.0),
+            Point2::new(1.0, 1.0),
+            Point2::new(-1.0, 0.0),
+        ] {
+            assert!(triangulation.move_vertex(v1, new_position)?);
+            positions[1] = new_position;
+            triangulation.sanity_check();
+            check_positions(&triangulation, &positions);
+        }
+
+        // Moving to the current position
+        assert!(triangulation.move_vertex(v1, Point2::new(-1.0, 0.0))?);
+        check_positions(&triangulation, &positions);
+
+        let mut triangulation = DelaunayTriangulation::<_>::new();
+        triangulation.insert(Point2::new(0.0, 0.0))?;
+        assert!(triangulation.move_vertex(v0, Point2::new(1.0, 2.0))?);
+        check_positions(&triangulation, &[Point2::new(1.0, 2.0)]);
+        Ok(())
+    }
 }
@@ -1,7 +1,7 @@
 use super::delaunay_core::Dcel;
 use crate::{
-    handles::VertexHandle, HasPosition, HintGenerator, LastUsedVertexHintGenerator,
-    NaturalNeighbor, Point2, Triangulation, TriangulationExt,
+    handles::VertexHandle, HasPosition, HintGenerator, InverseDistanceWeighting,
+    LastUsedVertexHintGenerator, NaturalNeighbor, Point2, Triangulation, TriangulationExt,
 };
 
 use num_traits::Float;
@@ -188,6 +188,10 @@ use serde::{Deserialize, Serialize};
 ///  * [UndirectedEdgeHandle::as_voronoi_edge()](crate::handles::UndirectedEdgeHandle::as_voronoi_edge())
 ///  * [UndirectedVoronoiEdge::as_delaunay_edge()](crate::handles::UndirectedVoronoiEdge::as_delaunay_edge())
 ///
+/// **Clipping**
+///  * [VoronoiFace::clip_to_rectangle()](crate::handles::VoronoiFace::clip_to_rectangle())
+///  * [VoronoiFace::clip_to_polygon()](crate::handles::VoronoiFace::clip_to_polygon())
+///
 /// ## Extracting the Voronoi Diagram (Example)
 /// Extracting the geometry of the voronoi diagram can be slightly tricky as some of the voronoi
 /// extend into infinity (see the dashed lines in the example above).
@@ -225,6 +229,25 @@ use serde::{Deserialize, Serialize};
 /// }
 /// ```
 ///
+/// Alternatively, each Voronoi cell can be clipped to a bounded region. The resulting cells are
+/// closed polygons:
+///
+/// ```
+/// use spade::{DelaunayTriangulation, Point2, Triangulation};
+///
+/// fn log_voronoi_cells(triangulation: &DelaunayTriangulation<Point2<f64>>) {
+///     let lower = Point2::new(-10.0, -10.0);
+///     let upper = Point2::new(10.0, 10.0);
+///     for face in triangulation.voronoi_faces() {
+///         println!(
+///             "Cell of {:?}: {:?}",
+///             face.as_delaunay_vertex().position(),
+///             face.clip_to_rectangle(lower, upper)
+///         );
+///     }
+/// }
+/// ```
+///
 /// # Performance tuning
 ///
 /// Fine-tuning a Delaunay triangulation can be more tricky from time to time. However, some will *nearly always* be
@@ -335,6 +358,12 @@ where
     pub fn natural_neighbor(&self) -> NaturalNeighbor<Self> {
         NaturalNeighbor::new(self)
     }
+
+    /// Allows using inverse distance weighting on this triangulation. Refer to the documentation
+    /// of [InverseDistanceWeighting] for more information.
+    pub fn inverse_distance_weighting(&self) -> InverseDistanceWeighting<'_, Self> {
+        InverseDistanceWeighting::new(self)
+    }
 }
 
 impl<V, DE, UE, F, L> Triangulation for DelaunayTriangulation<V, DE, UE, F, L>
@@ -372,7 +401,12 @@ where
 mod test {
     use crate::test_utilities::{random_points_with_seed, SEED};
 
-    use crate::{DelaunayTriangulation, InsertionError, Point2, Triangulation};
+    use alloc::vec::Vec;
+
+    use crate::{
+        DelaunayTriangulation, FloatTriangulation, InsertionError, Point2, Triangulation,
+        TriangulationExt,
+    };
 
     #[allow(unused)]
     #[cfg(feature = "serde")]
@@ -420,6 +454,154 @@ mod test {
         Ok(())
     }
 
+    fn polygon_area(polygon: &[Point2<f64>]) -> f64 {
+        let mut result = 0.0;
+        for (index, from) in polygon.iter().enumerate() {
+            let to = polygon[(index + 1) % polygon.len()];
+            result += from.x * to.y - to.x * from.y;
+        }
+        result * 0.5
+    }
+
+    #[test]
+    fn test_clip_voronoi_faces() -> Result<(), InsertionError> {
+        let points = random_points_with_seed(100, SEED);
+        let d = DelaunayTriangulation::<_>::bulk_load(points)?;
+
+        let lower = Point2::new(-0.5, -0.8);
+        let upper = Point2::new(0.7, 0.6);
+        let mut total_area = 0.0;
+        for face in d.voronoi_faces() {
+            let cell = face.clip_to_rectangle(lower, upper);
+            let area = polygon_area(&cell);
+            assert!(area >= 0.0);
+            total_area += area;
+
+            let site = face.as_delaunay_vertex().position();
+            for vertex in &cell {
+                // Any point of a cell must be closer to its site than to any other vertex
+                let nearest = d.nearest_neighbor(*vertex).unwrap();
+                assert!(site.distance_2(*vertex) <= nearest.position().distance_2(*vertex) + 1e-10);
+            }
+        }
+        assert!((total_area - 1.2 * 1.4).abs() < 1e-10);
+        Ok(())
+    }
+
+    #[test]
+    fn test_clip_voronoi_faces_to_polygon() -> Result<(), InsertionError> {
+        let points = random_points_with_seed(50, SEED);
+        let d = DelaunayTriangulation::<_>::bulk_load(points)?;
+
+        // A clockwise hexagon
+        let polygon: Vec<_> = (0..6)
+            .rev()
+            .map(|i| {
+                let angle = i as f64 * core::f64::consts::PI / 3.0;
+                Point2::new(angle.cos() * 0.8, angle.sin() * 0.8)
+            })
+            .collect();
+
+        let total_area: f64 = d
+            .voronoi_faces()
+            .map(|face| polygon_area(&face.clip_to_polygon(&polygon)))
+            .sum();
+
+        assert!((total_area + polygon_area(&polygon)).abs() < 1e-10);
+        Ok(())
+    }
+
+    #[test]
+    fn test_clip_voronoi_faces_degenerate() -> Result<(), InsertionError> {
+        let lower = Point2::new(-2.0, -2.0);
+        let upper = Point2::new(2.0, 2.0);
+
+        let mut d = DelaunayTriangulation::<_>::new();
+        d.insert(Point2::new(0.5, 0.5))?;
+        let cell = d
+            .voronoi_faces()
+            .next()
+            .unwrap()
+            .clip_to_rectangle(lower, upper);
+        assert_eq!(polygon_area(&cell), 16.0);
+
+        // All vertices are collinear
+        d.insert(Point2::new(-0.5, -0.5))?;
+        d.insert(Point2::new(1.5, 1.5))?;
+        let areas: Vec<_> = d
+            .voronoi_faces()
+            .map(|face| polygon_area(&face.clip_to_rectangle(lower, upper)))
+            .collect();
+        assert_eq!(areas.iter().sum::<f64>(), 16.0);
+        assert!(areas.iter().all(|area| *area > 0.0));
+
+        // A cell outside of the rectangle
+        d.insert(Point2::new(10.0, 0.0))?;
+        let outside = d.voronoi_faces().next_back().unwrap();
+        assert_eq!(
+            outside.as_delaunay_vertex().position(),
+            Point2::new(10.0, 0.0)
+        );
+        assert!(outside.clip_to_rectangle(lower, upper).is_empty());
+        Ok(())
+    }
+
+    fn max_centroid_distance_2(
+        d: &DelaunayTriangulation<Point2<f64>>,
+        bounds: &[Point2<f64>],
+    ) -> f64 {
+        d.voronoi_faces()
+            .map(|face| {
+                let cell = face.clip_to_polygon(bounds);
+                let centroid = crate::delaunay_core::math::polygon_centroid(&cell).unwrap();
+                centroid.distance_2(face.as_delaunay_vertex().position())
+            })
+            .fold(0.0, f64::max)
+    }
+
+    #[test]
+    fn test_lloyd_relaxation() -> Result<(), InsertionError> {
+        let bounds = [
+            Point2::new(-1.0, -1.0),
+            Point2::new(1.0, -1.0),
+            Point2::new(1.0, 1.0),
+            Point2::new(-1.0, 1.0),
+        ];
+        let mut d = DelaunayTriangulation::<_>::bulk_load(random_points_with_seed(100, SEED))?;
+        let initial_distance = max_centroid_distance_2(&d, &bounds);
+
+        d.lloyd_relaxation(20, &bounds);
+        d.sanity_check();
+        assert_eq!(d.num_vertices(), 100);
+        for vertex in d.vertices() {
+            let position = vertex.position();
+            assert!(position.x.abs() <= 1.0 && position.y.abs() <= 1.0);
+        }
+        assert!(max_centroid_distance_2(&d, &bounds) < initial_distance * 0.1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_lloyd_relaxation_with_fixed_vertices() -> Result<(), InsertionError> {
+        let bounds = [
+            Point2::new(-1.0, -1.0),
+            Point2::new(1.0, -1.0),
+            Point2::new(1.0, 1.0),
+            Point2::new(-1.0, 1.0),
+        ];
+        let points = random_points_with_seed(50, SEED);
+        let fixed: Vec<_> = points.iter().copied().filter(|p| p.x < 0.0).collect();
+        let mut d = DelaunayTriangulation::<_>::bulk_load(points)?;
+
+        d.lloyd_relaxation_with_fixed_vertices(5, &bounds, |vertex| vertex.position().x < 0.0);
+        d.sanity_check();
+        assert_eq!(d.num_vertices(), 50);
+        for position in fixed {
+            assert!(d.locate_vertex(position).is_some());
+        }
+        Ok(())
+    }
+
     #[test]
     #[allow(clippy::redundant_clone)]
     #[allow(unused_must_use)]
@@ -6,8 +6,10 @@
 //! * A 2D Delaunay triangulation: [DelaunayTriangulation]
 //! * Uses exact geometric predicate evaluation, preventing construction errors due to precision loss.
 //! * A 2D constrained Delaunay triangulation: [ConstrainedDelaunayTriangulation]
+//! * A 2D regular (weighted Delaunay) triangulation and power diagram: [RegularTriangulation]
 //! * Supports vertex removal
 //! * Serde support with the `serde` feature.
+//! * Parallel bulk loading with the `rayon` feature.
 //! * `no_std` support with `default-features = false`
 //! * Natural neighbor interpolation: [NaturalNeighbor]
 
@@ -29,12 +31,14 @@ mod delaunay_triangulation;
 mod flood_fill_iterator;
 mod intersection_iterator;
 mod point;
+mod regular_triangulation;
 
 mod triangulation;
 
-pub use crate::cdt::{CdtEdge, ConstrainedDelaunayTriangulation};
+pub use crate::cdt::{CdtBulkLoadError, CdtEdge, ConstrainedDelaunayTriangulation, FillRule};
 pub use crate::delaunay_triangulation::DelaunayTriangulation;
-pub use crate::point::{HasPosition, Point2, SpadeNum};
+pub use crate::point::{HasPosition, HasPositionMut, HasWeight, Point2, SpadeNum, WeightedPoint2};
+pub use crate::regular_triangulation::{RegularInsertionResult, RegularTriangulation};
 
 pub use crate::delaunay_core::math::{
     mitigate_underflow, validate_coordinate, validate_vertex, InsertionError, PointProjection,
@@ -42,13 +46,20 @@ pub use crate::delaunay_core::math::{
 };
 
 pub use delaunay_core::{
-    AngleLimit, HierarchyHintGenerator, HierarchyHintGeneratorWithBranchFactor, HintGenerator,
-    LastUsedVertexHintGenerator, RefinementParameters, RefinementResult,
+    AngleLimit, ConstraintSplit, HierarchyHintGenerator, HierarchyHintGeneratorWithBranchFactor,
+    HintGenerator, LastUsedVertexHintGenerator, QualityDistribution, QualityReport,
+    RefinementEvent, RefinementHint, RefinementParameters, RefinementProgress, RefinementResult,
+    SmoothingResult, NUM_ANGLE_HISTOGRAM_BINS,
 };
 
-pub use crate::delaunay_core::interpolation::{Barycentric, NaturalNeighbor};
+pub use crate::delaunay_core::interpolation::{
+    Barycentric, CloughTocher, Extrapolation, GradientEstimation, Interpolatable,
+    InterpolationGrid, InverseDistanceWeighting, NaturalNeighbor,
+};
 pub use delaunay_core::LineSideInfo;
-pub use triangulation::{FloatTriangulation, PositionInTriangulation, Triangulation};
+pub use triangulation::{
+    FloatTriangulation, PositionInTriangulation, StableBulkLoadError, Triangulation,
+};
 
 #[cfg(not(fuzzing))]
 pub(crate) use delaunay_core::TriangulationExt;
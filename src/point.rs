@@ -138,3 +138,74 @@ impl<S: SpadeNum> HasPosition for Point2<S> {
         *self
     }
 }
+
+/// An object whose position can be changed.
+///
+/// Vertices need to implement this trait to allow being moved with
+/// [Triangulation::move_vertex](crate::Triangulation::move_vertex).
+pub trait HasPositionMut: HasPosition {
+    /// Sets the position of this object.
+    fn set_position(&mut self, position: Point2<Self::Scalar>);
+}
+
+impl<S: SpadeNum> HasPositionMut for Point2<S> {
+    fn set_position(&mut self, position: Point2<S>) {
+        *self = position;
+    }
+}
+
+/// An object with a position and a weight.
+///
+/// Vertices need to implement this trait to allow being inserted into a
+/// [RegularTriangulation](crate::RegularTriangulation).
+pub trait HasWeight: HasPosition {
+    /// Returns the weight of this object.
+    ///
+    /// **Note**: Just like the position, the weight must not change once the object has been
+    /// inserted into a triangulation.
+    fn weight(&self) -> Self::Scalar;
+}
+
+/// A two dimensional point with a weight.
+///
+/// This is the basic vertex type used for [regular triangulations](crate::RegularTriangulation).
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy, Default, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde")
+)]
+pub struct WeightedPoint2<S> {
+    /// The point's position
+    pub position: Point2<S>,
+    /// The point's weight
+    pub weight: S,
+}
+
+impl<S> WeightedPoint2<S> {
+    /// Creates a new weighted point.
+    #[inline]
+    pub const fn new(position: Point2<S>, weight: S) -> Self {
+        WeightedPoint2 { position, weight }
+    }
+}
+
+impl<S: SpadeNum> HasPosition for WeightedPoint2<S> {
+    type Scalar = S;
+
+    fn position(&self) -> Point2<S> {
+        self.position
+    }
+}
+
+impl<S: SpadeNum> HasPositionMut for WeightedPoint2<S> {
+    fn set_position(&mut self, position: Point2<S>) {
+        self.position = position;
+    }
+}
+
+impl<S: SpadeNum> HasWeight for WeightedPoint2<S> {
+    fn weight(&self) -> S {
+        self.weight
+    }
+}
@@ -0,0 +1,1102 @@
+use super::delaunay_core::Dcel;
+use crate::delaunay_core::{dcel_operations, math};
+use crate::{
+    handles::{
+        DirectedEdgeHandle, FixedDirectedEdgeHandle, FixedFaceHandle, FixedVertexHandle, InnerTag,
+        VertexHandle,
+    },
+    HasPosition, HasPositionMut, HasWeight, HintGenerator, InsertionError,
+    LastUsedVertexHintGenerator, Point2, PositionInTriangulation, StableBulkLoadError,
+    Triangulation, TriangulationExt,
+};
+
+use alloc::vec;
+use alloc::vec::Vec;
+use hashbrown::HashSet;
+use num_traits::zero;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Describes the outcome of [RegularTriangulation::insert_and_report_hidden].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum RegularInsertionResult<V> {
+    /// The vertex has been inserted.
+    Inserted {
+        /// The handle of the inserted vertex.
+        vertex: FixedVertexHandle,
+        /// All vertices that have become hidden by the inserted vertex. These vertices have been
+        /// removed from the triangulation.
+        hidden_vertices: Vec<V>,
+    },
+    /// The vertex is hidden by the existing vertices and has not been inserted.
+    Hidden(V),
+}
+
+/// A two dimensional [regular triangulation](https://en.wikipedia.org/wiki/Weighted_Voronoi_diagram),
+/// also known as *weighted Delaunay triangulation*.
+///
+/// Every vertex of a regular triangulation carries a weight (see [HasWeight]). The
+/// *power distance* between a vertex `v` and a position `p` is defined as
+/// `|p - v|² - weight(v)`. A regular triangulation is the dual graph of the *power diagram*
+/// which assigns each position to the vertex with the smallest power distance. If all weights
+/// are equal, the regular triangulation is a Delaunay triangulation.
+///
+/// Unlike in a Delaunay triangulation, a vertex can be *hidden*: If its power cell is empty, the
+/// vertex is not part of the regular triangulation. Hidden vertices are not stored.
+/// Use [RegularTriangulation::insert_and_report_hidden] to learn which vertices have been hidden
+/// by an insertion. Removing a vertex does not restore any vertices that it has previously hidden.
+///
+/// Most methods on this type require the [Triangulation] trait. The following methods behave
+/// differently compared to other triangulations:
+/// - If the inserted vertex is hidden, [Triangulation::insert] drops it and returns the handle
+///   of the vertex at the same position or, if there is none, of the vertex whose power cell
+///   contains it.
+/// - Any insertion may hide and remove other vertices. **This can invalidate fixed vertex
+///   handles**, just like [Triangulation::remove].
+/// - Bulk loading inserts all vertices incrementally. [Triangulation::bulk_load_stable] fails
+///   if any input vertex is hidden. [Triangulation::insert_bulk] maps any hidden input vertex
+///   to the vertex that [Triangulation::insert] would return.
+/// - [Triangulation::move_vertex] removes and re-inserts the vertex and can invalidate vertex handles.
+///   It returns `Ok(false)` if the vertex would be hidden at its new position. The vertex
+///   remains at its old position in this case.
+///
+/// # Power diagram
+/// The power diagram can be extracted in the same way as a [Voronoi diagram](crate::DelaunayTriangulation#voronoi-diagram).
+/// Use [VoronoiVertex::power_center](crate::handles::VoronoiVertex::power_center) instead of
+/// [VoronoiVertex::position](crate::handles::VoronoiVertex::position) to get the position of a
+/// power diagram vertex.
+/// Use [VoronoiFace::clip_power_cell_to_polygon](crate::handles::VoronoiFace::clip_power_cell_to_polygon)
+/// to extract bounded power diagram cells.
+///
+/// # Example
+/// ```
+/// use spade::{RegularTriangulation, Triangulation, WeightedPoint2, Point2, RegularInsertionResult};
+///
+/// # fn main() -> Result<(), spade::InsertionError> {
+/// let mut triangulation: RegularTriangulation<WeightedPoint2<f64>> = RegularTriangulation::new();
+/// for (x, y) in [(0.0, 0.0), (4.0, 0.0), (0.0, 4.0), (4.0, 4.0), (2.0, 2.0)] {
+///     triangulation.insert(WeightedPoint2::new(Point2::new(x, y), 0.0))?;
+/// }
+///
+/// // This vertex is hidden by the other vertices
+/// let light = WeightedPoint2::new(Point2::new(2.0, 1.0), -10.0);
+/// assert_eq!(
+///     triangulation.insert_and_report_hidden(light)?,
+///     RegularInsertionResult::Hidden(light)
+/// );
+///
+/// // This vertex hides the vertex at (2, 2)
+/// let heavy = WeightedPoint2::new(Point2::new(2.0, 1.0), 10.0);
+/// let result = triangulation.insert_and_report_hidden(heavy)?;
+/// let center = WeightedPoint2::new(Point2::new(2.0, 2.0), 0.0);
+/// assert!(matches!(
+///     result,
+///     RegularInsertionResult::Inserted { hidden_vertices, .. } if hidden_vertices == [center]
+/// ));
+/// assert_eq!(triangulation.num_vertices(), 5);
+/// # Ok(()) }
+/// ```
+#[doc(alias = "Weighted Delaunay")]
+#[doc(alias = "Power diagram")]
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde")
+)]
+pub struct RegularTriangulation<V, DE = (), UE = (), F = (), L = LastUsedVertexHintGenerator>
+where
+    V: HasWeight,
+    DE: Default,
+    UE: Default,
+    F: Default,
+    L: HintGenerator<<V as HasPosition>::Scalar>,
+{
+    dcel: Dcel<V, DE, UE, F>,
+    hint_generator: L,
+}
+
+impl<V, DE, UE, F, L> Default for RegularTriangulation<V, DE, UE, F, L>
+where
+    V: HasWeight,
+    DE: Default,
+    UE: Default,
+    F: Default,
+    L: HintGenerator<<V as HasPosition>::Scalar>,
+{
+    fn default() -> Self {
+        Self {
+            dcel: Default::default(),
+            hint_generator: Default::default(),
+        }
+    }
+}
+
+/// A face of a regular triangulation or an infinite face adjacent to a convex hull edge.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+enum ConflictUnit {
+    Face(FixedFaceHandle<InnerTag>),
+    Outer(FixedDirectedEdgeHandle),
+}
+
+impl ConflictUnit {
+    fn of<V, DE, UE, F>(edge: DirectedEdgeHandle<V, DE, UE, F>) -> Self {
+        match edge.face().as_inner() {
+            Some(face) => ConflictUnit::Face(face.fix()),
+            None => ConflictUnit::Outer(edge.fix()),
+        }
+    }
+
+    fn around<'a, V, DE, UE, F>(
+        vertex: VertexHandle<'a, V, DE, UE, F>,
+    ) -> impl Iterator<Item = Self> + 'a {
+        vertex.out_edges().flat_map(|edge| {
+            let unit = Self::of(edge);
+            let incoming = match unit {
+                ConflictUnit::Face(_) => None,
+                ConflictUnit::Outer(_) => Some(ConflictUnit::Outer(edge.prev().fix())),
+            };
+            core::iter::once(unit).chain(incoming)
+        })
+    }
+}
+
+fn weighted<V: HasWeight>(vertex: &V) -> (Point2<V::Scalar>, V::Scalar) {
+    (vertex.position(), vertex.weight())
+}
+
+impl<V, DE, UE, F, L> RegularTriangulation<V, DE, UE, F, L>
+where
+    V: HasWeight,
+    DE: Default,
+    UE: Default,
+    F: Default,
+    L: HintGenerator<<V as HasPosition>::Scalar>,
+{
+    /// Inserts a new vertex and reports which vertices have been hidden.
+    ///
+    /// Returns [RegularInsertionResult::Hidden] if the vertex is hidden by the existing vertices.
+    /// Otherwise, all vertices hidden by the new vertex are removed and returned.
+    /// A vertex at the same position as an existing vertex replaces it only if its weight is
+    /// larger.
+    ///
+    /// Returns an error if the vertex's position is invalid, see [Triangulation::insert].
+    pub fn insert_and_report_hidden(
+        &mut self,
+        vertex: V,
+    ) -> Result<RegularInsertionResult<V>, InsertionError> {
+        self.insert_regular(vertex, None)
+    }
+
+    fn insert_regular(
+        &mut self,
+        vertex: V,
+        hint: Option<FixedVertexHandle>,
+    ) -> Result<RegularInsertionResult<V>, InsertionError> {
+        use PositionInTriangulation::*;
+
+        math::validate_vertex(&vertex)?;
+        let location = self.locate_with_hint_option_core(vertex.position(), hint);
+
+        let is_hidden = match location {
+            OnVertex(existing) => self.vertex(existing).data().weight() >= vertex.weight(),
+            OnFace(face) => {
+                let [v0, v1, v2] = self.face(face).vertices();
+                math::power_circle_test(
+                    weighted(v0.data()),
+                    weighted(v1.data()),
+                    weighted(v2.data()),
+                    weighted(&vertex),
+                ) < 0.0
+            }
+            OnEdge(edge) => {
+                let edge = self.directed_edge(edge);
+                math::power_segment_test(
+                    weighted(edge.from().data()),
+                    weighted(edge.to().data()),
+                    weighted(&vertex),
+                ) < 0.0
+            }
+            OutsideOfConvexHull(_) | NoTriangulation => false,
+        };
+
+        if is_hidden {
+            return Ok(RegularInsertionResult::Hidden(vertex));
+        }
+
+        // Vertices hidden by the new vertex are removed first. No vertex will become hidden
+        // during the insertion which can then be performed by edge flips only.
+        let hidden_positions = self.find_hidden_vertices(&vertex, location);
+        let mut hidden_vertices = Vec::with_capacity(hidden_positions.len());
+        for position in hidden_positions {
+            if let Some(hidden_vertex) = self.locate_vertex(position) {
+                let hidden_vertex = hidden_vertex.fix();
+                hidden_vertices.push(self.remove_regular(hidden_vertex));
+            }
+        }
+
+        let hint = match location {
+            _ if !hidden_vertices.is_empty() => None,
+            OnFace(face) => Some(self.face(face).vertices()[0].fix()),
+            OnEdge(edge) | OutsideOfConvexHull(edge) => Some(self.directed_edge(edge).from().fix()),
+            _ => None,
+        };
+        let new_vertex = self.insert_with_hint_option(vertex, hint)?;
+
+        Ok(RegularInsertionResult::Inserted {
+            vertex: new_vertex,
+            hidden_vertices,
+        })
+    }
+
+    /// Returns the positions of all vertices that are hidden by a new vertex.
+    ///
+    /// The power circles of the faces around a hidden vertex all contain the new vertex.
+    /// These faces form a connected region which is found with a flood fill starting at the new
+    /// vertex's location. Each convex hull edge is treated as an infinite face which
+    /// contains all positions on its outer side.
+    fn find_hidden_vertices(
+        &self,
+        vertex: &V,
+        location: PositionInTriangulation,
+    ) -> Vec<Point2<V::Scalar>> {
+        use PositionInTriangulation::*;
+
+        let initial_units: Vec<_> = match location {
+            OnFace(face) => vec![ConflictUnit::Face(face)],
+            OnEdge(edge) => {
+                let edge = self.directed_edge(edge);
+                vec![ConflictUnit::of(edge), ConflictUnit::of(edge.rev())]
+            }
+            OnVertex(existing) => ConflictUnit::around(self.vertex(existing)).collect(),
+            OutsideOfConvexHull(edge) => vec![ConflictUnit::Outer(edge)],
+            NoTriangulation => Vec::new(),
+        };
+
+        let mut visited = HashSet::new();
+        let mut conflicts = HashSet::new();
+        let mut stack = Vec::new();
+        for unit in initial_units {
+            if visited.insert(unit) && self.is_in_conflict(unit, vertex) {
+                conflicts.insert(unit);
+                stack.push(unit);
+            }
+        }
+
+        let mut candidates = Vec::new();
+        while let Some(unit) = stack.pop() {
+            let (edges, neighbors) = match unit {
+                ConflictUnit::Face(face) => {
+                    let edges = self.face(face).adjacent_edges();
+                    (edges, edges.map(|edge| ConflictUnit::of(edge.rev())))
+                }
+                ConflictUnit::Outer(edge) => {
+                    let edge = self.directed_edge(edge);
+                    (
+                        [edge, edge.next(), edge.prev()],
+                        [
+                            ConflictUnit::of(edge.rev()),
+                            ConflictUnit::Outer(edge.next().fix()),
+                            ConflictUnit::Outer(edge.prev().fix()),
+                        ],
+                    )
+                }
+            };
+            candidates.extend(edges.iter().map(|edge| edge.from().fix()));
+
+            for neighbor in neighbors {
+                if visited.insert(neighbor) && self.is_in_conflict(neighbor, vertex) {
+                    conflicts.insert(neighbor);
+                    stack.push(neighbor);
+                }
+            }
+        }
+
+        candidates.sort_unstable();
+        candidates.dedup();
+        candidates
+            .into_iter()
+            .map(|candidate| self.vertex(candidate))
+            .filter(|candidate| {
+                let is_existing =
+                    matches!(location, OnVertex(existing) if existing == candidate.fix());
+                is_existing
+                    || ConflictUnit::around(*candidate).all(|unit| conflicts.contains(&unit))
+                        && !self.is_end_point_beside(*candidate, vertex.position())
+            })
+            .map(|candidate| candidate.position())
+            .collect()
+    }
+
+    /// Returns `true` if a vertex is the end point of a line of collinear vertices and the
+    /// position lies on its inner side.
+    ///
+    /// Such a vertex is part of the convex hull even if it is in conflict with both of its
+    /// infinite faces.
+    fn is_end_point_beside(
+        &self,
+        vertex: VertexHandle<V, DE, UE, F>,
+        position: Point2<V::Scalar>,
+    ) -> bool {
+        let mut out_edges = vertex.out_edges();
+        match (out_edges.next(), out_edges.next()) {
+            (Some(edge), None) => {
+                let origin = vertex.position();
+                let direction = edge.to().position().sub(origin);
+                position.sub(origin).dot(direction) >= zero()
+            }
+            _ => false,
+        }
+    }
+
+    fn is_in_conflict(&self, unit: ConflictUnit, vertex: &V) -> bool {
+        match unit {
+            ConflictUnit::Face(face) => {
+                let [v0, v1, v2] = self.face(face).vertices();
+                math::power_circle_test(
+                    weighted(v0.data()),
+                    weighted(v1.data()),
+                    weighted(v2.data()),
+                    weighted(vertex),
+                ) > 0.0
+            }
+            ConflictUnit::Outer(edge) => {
+                let edge = self.directed_edge(edge);
+                let side = edge.side_query(vertex.position());
+                if side.is_on_line() {
+                    math::power_segment_test(
+                        weighted(edge.from().data()),
+                        weighted(edge.to().data()),
+                        weighted(vertex),
+                    ) > 0.0
+                } else {
+                    side.is_on_left_side()
+                }
+            }
+        }
+    }
+
+    /// Removes a vertex from the triangulation.
+    ///
+    /// Filling the hole of an inner vertex with a triangle fan can create overlapping
+    /// triangles. Unlike for Delaunay triangulations, flipping edges does not always resolve
+    /// these as collinear vertices may become coplanar after lifting. Instead, the degree of the
+    /// vertex is reduced by flipping its edges until it can be removed without creating
+    /// any new edge. The ears around the vertex are created in the order in which they would
+    /// become legal if the vertex's weight was decreased continuously, see
+    ///
+    /// Olivier Devillers. On Deletion in Delaunay Triangulations.
+    /// <https://doi.org/10.1142/S0218195902000773>
+    fn remove_regular(&mut self, vertex: FixedVertexHandle) -> V {
+        let is_inner = |this: &Self| {
+            this.num_all_faces() > 1
+                && !this
+                    .vertex(vertex)
+                    .out_edges()
+                    .any(|edge| edge.is_outer_edge())
+        };
+
+        let mut link: Vec<_> = self
+            .vertex(vertex)
+            .out_edges()
+            .map(|edge| edge.to().fix())
+            .collect();
+
+        while is_inner(self) && self.vertex(vertex).out_edges().nth(3).is_some() {
+            let center = self.vertex(vertex);
+            let mut best_ear = None;
+            for edge in center.out_edges() {
+                let [previous, current, next] = [edge.cw().to(), edge.to(), edge.ccw().to()];
+                // The ear must be convex and must not contain the removed vertex. The removed
+                // vertex may lie on the ear's base, the resulting flat triangle vanishes once
+                // the vertex is removed.
+                let is_ear =
+                    math::side_query(previous.position(), next.position(), center.position())
+                        .is_on_left_side_or_on_line()
+                        && math::side_query(
+                            previous.position(),
+                            next.position(),
+                            current.position(),
+                        )
+                        .is_on_right_side();
+                if !is_ear {
+                    continue;
+                }
+
+                // The power test is proportional to the ear's area and to the distance between
+                // the lifted vertex and the ear's lifted plane
+                let area: f64 =
+                    math::triangle_area([previous.position(), current.position(), next.position()])
+                        .into();
+                let priority = math::power_circle_test(
+                    weighted(previous.data()),
+                    weighted(current.data()),
+                    weighted(next.data()),
+                    weighted(center.data()),
+                ) / area;
+
+                match best_ear {
+                    Some((_, best_priority)) if best_priority <= priority => {}
+                    _ => best_ear = Some((edge.fix().as_undirected(), priority)),
+                }
+            }
+
+            let (edge, _) = best_ear.expect("An inner vertex always has a flippable edge");
+            dcel_operations::flip_cw(self.s_mut(), edge);
+        }
+
+        let result = self.remove_and_notify(vertex);
+
+        // The last vertex has been swapped into the removed vertex's slot
+        let swapped_in_vertex = FixedVertexHandle::new(self.num_vertices());
+        for neighbor in &mut link {
+            if *neighbor == swapped_in_vertex {
+                *neighbor = vertex;
+            }
+        }
+
+        // Nearly equal priorities may have been ordered incorrectly due to rounding. Any edge
+        // of the hole that is left illegal is fixed by flipping.
+        let mut edges: Vec<_> = link
+            .iter()
+            .flat_map(|neighbor| self.vertex(*neighbor).out_edges())
+            .filter(|edge| link.contains(&edge.to().fix()))
+            .map(|edge| edge.fix().as_undirected())
+            .collect();
+        while let Some(fixed_edge) = edges.pop() {
+            let edge = self.directed_edge(fixed_edge.as_directed());
+            if let (Some(left), Some(right)) =
+                (edge.opposite_vertex(), edge.rev().opposite_vertex())
+            {
+                let [from, to] = edge.vertices();
+                if self.contained_in_circumference(
+                    from.data(),
+                    to.data(),
+                    left.data(),
+                    right.data(),
+                ) {
+                    let quad_edges = [
+                        edge.next(),
+                        edge.prev(),
+                        edge.rev().next(),
+                        edge.rev().prev(),
+                    ]
+                    .map(|edge| edge.fix().as_undirected());
+                    dcel_operations::flip_cw(self.s_mut(), fixed_edge);
+                    edges.extend(quad_edges);
+                }
+            }
+        }
+
+        result
+    }
+
+    fn insert_or_find_owner(
+        &mut self,
+        vertex: V,
+        hint: Option<FixedVertexHandle>,
+    ) -> Result<FixedVertexHandle, InsertionError> {
+        match self.insert_regular(vertex, hint)? {
+            RegularInsertionResult::Inserted { vertex, .. } => Ok(vertex),
+            RegularInsertionResult::Hidden(vertex) => Ok(self.find_owner(vertex.position(), hint)),
+        }
+    }
+
+    /// Returns the vertex at a position or, if there is none, the vertex whose power cell
+    /// contains the position.
+    fn find_owner(
+        &self,
+        position: Point2<V::Scalar>,
+        hint: Option<FixedVertexHandle>,
+    ) -> FixedVertexHandle {
+        if let Some(existing) = self.locate_vertex(position) {
+            return existing.fix();
+        }
+        let start = hint.unwrap_or_else(|| self.hint_generator().get_hint(position));
+        let start = self.validate_vertex_handle(start);
+        self.walk_to_power_nearest_neighbor(start, position).fix()
+    }
+
+    /// Returns the vertex with the smallest power distance to a position.
+    ///
+    /// A greedy walk is sufficient: The power distance to the lifted vertices is a linear
+    /// function and the regular triangulation is the projection of a convex surface.
+    fn walk_to_power_nearest_neighbor(
+        &self,
+        start: FixedVertexHandle,
+        position: Point2<V::Scalar>,
+    ) -> VertexHandle<'_, V, DE, UE, F> {
+        let power_distance = |vertex: VertexHandle<V, DE, UE, F>| {
+            vertex.position().distance_2(position) - vertex.data().weight()
+        };
+
+        let mut current = self.vertex(start);
+        let mut current_distance = power_distance(current);
+        loop {
+            let mut improved = false;
+            for neighbor in current.out_edges().map(|edge| edge.to()) {
+                let distance = power_distance(neighbor);
+                if distance < current_distance {
+                    current = neighbor;
+                    current_distance = distance;
+                    improved = true;
+                }
+            }
+
+            if !improved {
+                return current;
+            }
+        }
+    }
+}
+
+impl<V, DE, UE, F, L> Triangulation for RegularTriangulation<V, DE, UE, F, L>
+where
+    V: HasWeight,
+    DE: Default,
+    UE: Default,
+    F: Default,
+    L: HintGenerator<<V as HasPosition>::Scalar>,
+{
+    type Vertex = V;
+    type DirectedEdge = DE;
+    type UndirectedEdge = UE;
+    type Face = F;
+    type HintGenerator = L;
+
+    fn s(&self) -> &Dcel<V, DE, UE, F> {
+        &self.dcel
+    }
+
+    fn s_mut(&mut self) -> &mut Dcel<V, DE, UE, F> {
+        &mut self.dcel
+    }
+
+    fn hint_generator(&self) -> &Self::HintGenerator {
+        &self.hint_generator
+    }
+
+    fn hint_generator_mut(&mut self) -> &mut Self::HintGenerator {
+        &mut self.hint_generator
+    }
+
+    fn contained_in_circumference(&self, v1: &V, v2: &V, v3: &V, p: &V) -> bool {
+        // Unlike in a Delaunay triangulation, an edge violating the power criterion may be
+        // adjacent to a non convex quad. Flipping it would create overlapping faces.
+        math::contained_in_power_circle(weighted(v1), weighted(v2), weighted(v3), weighted(p))
+            && math::is_edge_flippable(v1.position(), v2.position(), v3.position(), p.position())
+    }
+
+    fn bulk_load(elements: Vec<V>) -> Result<Self, InsertionError> {
+        let mut result = Self::new();
+        for element in elements {
+            result.insert(element)?;
+        }
+        Ok(result)
+    }
+
+    #[cfg(feature = "rayon")]
+    fn bulk_load_parallel(elements: Vec<V>) -> Result<Self, InsertionError>
+    where
+        Self: Send,
+        V: Send,
+    {
+        Self::bulk_load(elements)
+    }
+
+    fn bulk_load_stable(elements: Vec<V>) -> Result<Self, StableBulkLoadError> {
+        let keys: Vec<_> = elements.iter().map(weighted).collect();
+        let mut result = Self::bulk_load(elements)?;
+
+        // The first input element with the same position and weight as a vertex is the
+        // element that was inserted. Any other element has been merged into another vertex.
+        let mut owners = vec![None; result.num_vertices()];
+        let mut first_merged = None;
+        for (index, (position, weight)) in keys.into_iter().enumerate() {
+            match result.locate_vertex(position) {
+                Some(existing)
+                    if existing.data().weight() == weight
+                        && owners[existing.fix().index()].is_none() =>
+                {
+                    owners[existing.fix().index()] = Some(index);
+                }
+                _ => {
+                    first_merged.get_or_insert((index, position));
+                }
+            }
+        }
+
+        if let Some((index, position)) = first_merged {
+            let owner = result.find_owner(position, None);
+            let merged_into = owners[owner.index()].expect("Every vertex has an input element");
+            return Err(StableBulkLoadError::MergedElement { index, merged_into });
+        }
+
+        // No element has been merged, owners is a permutation of all input indices.
+        let new_indices: Vec<_> = owners
+            .into_iter()
+            .map(|owner| FixedVertexHandle::new(owner.expect("Every vertex has an input element")))
+            .collect();
+        dcel_operations::reorder_vertices(result.s_mut(), &new_indices);
+
+        let hint_generator = L::initialize_from_triangulation(&result);
+        *result.hint_generator_mut() = hint_generator;
+        Ok(result)
+    }
+
+    fn insert(&mut self, vertex: V) -> Result<FixedVertexHandle, InsertionError> {
+        self.insert_or_find_owner(vertex, None)
+    }
+
+    fn insert_bulk(&mut self, vertices: Vec<V>) -> Result<Vec<FixedVertexHandle>, InsertionError> {
+        // Later insertions may hide and remove previously inserted vertices. The handles are
+        // only looked up once all vertices have been inserted.
+        let positions: Vec<_> = vertices.iter().map(|vertex| vertex.position()).collect();
+        crate::delaunay_core::insert_bulk(self, vertices)?;
+        Ok(positions
+            .into_iter()
+            .map(|position| self.find_owner(position, None))
+            .collect())
+    }
+
+    fn locate_and_remove(&mut self, point: Point2<V::Scalar>) -> Option<V> {
+        let vertex = self.locate_vertex(point)?.fix();
+        Some(self.remove_regular(vertex))
+    }
+
+    fn remove(&mut self, vertex: FixedVertexHandle) -> V {
+        self.remove_regular(vertex)
+    }
+
+    fn insert_with_hint(
+        &mut self,
+        t: V,
+        hint: FixedVertexHandle,
+    ) -> Result<FixedVertexHandle, InsertionError> {
+        self.insert_or_find_owner(t, Some(hint))
+    }
+
+    fn move_vertex(
+        &mut self,
+        vertex: FixedVertexHandle,
+        new_position: Point2<V::Scalar>,
+    ) -> Result<bool, InsertionError>
+    where
+        V: HasPositionMut,
+    {
+        math::validate_coordinate(new_position.x)?;
+        math::validate_coordinate(new_position.y)?;
+
+        let old_position = self.vertex(vertex).position();
+        if old_position == new_position {
+            return Ok(true);
+        }
+
+        if self.locate_vertex(new_position).is_some() {
+            return Ok(false);
+        }
+
+        let mut data = self.remove(vertex);
+        data.set_position(new_position);
+        match self.insert_regular(data, None)? {
+            RegularInsertionResult::Inserted { .. } => Ok(true),
+            RegularInsertionResult::Hidden(mut data) => {
+                // The vertex was not hidden at its old position and can be restored.
+                data.set_position(old_position);
+                self.insert_regular(data, None)?;
+                Ok(false)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::RegularInsertionResult;
+    use crate::delaunay_core::math;
+    use crate::handles::FixedVertexHandle;
+    use crate::test_utilities::{random_points_with_seed, SEED, SEED2};
+    use crate::{
+        DelaunayTriangulation, HierarchyHintGenerator, InsertionError, Point2,
+        PositionInTriangulation, RegularTriangulation, StableBulkLoadError, Triangulation,
+        TriangulationExt, WeightedPoint2,
+    };
+
+    use alloc::{vec, vec::Vec};
+    use rand::distributions::{Distribution, Uniform};
+    use rand::SeedableRng;
+
+    type Rt = RegularTriangulation<WeightedPoint2<f64>>;
+
+    fn random_weighted_points(size: usize, seed: &[u8; 32]) -> Vec<WeightedPoint2<f64>> {
+        let mut rng = rand::rngs::StdRng::from_seed(*seed);
+        let weights = Uniform::new(0.0, 0.05);
+        random_points_with_seed(size, seed)
+            .into_iter()
+            .map(|position| WeightedPoint2::new(position, weights.sample(&mut rng)))
+            .collect()
+    }
+
+    fn assert_hidden(rt: &Rt, vertex: &WeightedPoint2<f64>) {
+        let weighted = |vertex: &WeightedPoint2<f64>| (vertex.position, vertex.weight);
+        let is_hidden = match rt.locate(vertex.position) {
+            PositionInTriangulation::OnVertex(existing) => {
+                rt.vertex(existing).data().weight >= vertex.weight
+            }
+            PositionInTriangulation::OnFace(face) => {
+                let [v0, v1, v2] = rt.face(face).vertices().map(|v| weighted(v.data()));
+                math::power_circle_test(v0, v1, v2, weighted(vertex)) <= 0.0
+            }
+            PositionInTriangulation::OnEdge(edge) => {
+                let [from, to] = rt
+                    .directed_edge(edge)
+                    .vertices()
+                    .map(|v| weighted(v.data()));
+                math::power_segment_test(from, to, weighted(vertex)) <= 0.0
+            }
+            _ => false,
+        };
+        assert!(is_hidden);
+    }
+
+    fn power_distance(vertex: &WeightedPoint2<f64>, position: Point2<f64>) -> f64 {
+        vertex.position.distance_2(position) - vertex.weight
+    }
+
+    #[test]
+    fn test_equal_weights_match_delaunay() -> Result<(), InsertionError> {
+        let points = random_points_with_seed(200, SEED);
+        let mut rt = Rt::new();
+        for point in &points {
+            rt.insert(WeightedPoint2::new(*point, 1.0))?;
+        }
+        rt.sanity_check();
+
+        let dt = DelaunayTriangulation::<Point2<f64>>::bulk_load_stable(points).unwrap();
+        assert_eq!(rt.num_vertices(), dt.num_vertices());
+        assert_eq!(rt.num_undirected_edges(), dt.num_undirected_edges());
+        for edge in dt.directed_edges() {
+            let [from, to] = [edge.from().fix(), edge.to().fix()];
+            assert!(rt.get_edge_from_neighbors(from, to).is_some());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_random_insertion() -> Result<(), InsertionError> {
+        let points = random_weighted_points(500, SEED);
+        let mut rt = Rt::new();
+        let mut hidden = Vec::new();
+        for point in points.iter().copied() {
+            match rt.insert_and_report_hidden(point)? {
+                RegularInsertionResult::Inserted {
+                    hidden_vertices, ..
+                } => hidden.extend(hidden_vertices),
+                RegularInsertionResult::Hidden(point) => hidden.push(point),
+            }
+        }
+        rt.sanity_check();
+        assert!(!hidden.is_empty());
+        assert_eq!(rt.num_vertices() + hidden.len(), points.len());
+
+        for point in &hidden {
+            assert_hidden(&rt, point);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_insert_hidden_vertex() -> Result<(), InsertionError> {
+        let mut rt = Rt::new();
+        let v0 = rt.insert(WeightedPoint2::new(Point2::new(0.0, 0.0), 0.0))?;
+        rt.insert(WeightedPoint2::new(Point2::new(4.0, 0.0), 0.0))?;
+
+        // Not hidden, the power cell lies between the segment's end points
+        let on_edge = WeightedPoint2::new(Point2::new(1.0, 0.0), -2.0);
+        let result = rt.insert_and_report_hidden(on_edge)?;
+        assert!(matches!(
+            result,
+            RegularInsertionResult::Inserted { hidden_vertices, .. } if hidden_vertices.is_empty()
+        ));
+        rt.remove(FixedVertexHandle::new(2));
+
+        // Hidden on an edge
+        let on_edge = WeightedPoint2::new(Point2::new(1.0, 0.0), -4.0);
+        assert_eq!(
+            rt.insert_and_report_hidden(on_edge)?,
+            RegularInsertionResult::Hidden(on_edge)
+        );
+        assert_eq!(rt.insert(on_edge)?, v0);
+
+        rt.insert(WeightedPoint2::new(Point2::new(0.0, 4.0), 0.0))?;
+
+        // Hidden in a face
+        let on_face = WeightedPoint2::new(Point2::new(0.5, 1.0), -5.0);
+        assert_eq!(
+            rt.insert_and_report_hidden(on_face)?,
+            RegularInsertionResult::Hidden(on_face)
+        );
+        assert_eq!(rt.insert(on_face)?, v0);
+
+        // Hidden by a vertex at the same position
+        let on_vertex = WeightedPoint2::new(Point2::new(0.0, 0.0), -1.0);
+        assert_eq!(
+            rt.insert_and_report_hidden(on_vertex)?,
+            RegularInsertionResult::Hidden(on_vertex)
+        );
+        assert_eq!(rt.insert(on_vertex)?, v0);
+        assert_eq!(rt.num_vertices(), 3);
+        rt.sanity_check();
+        Ok(())
+    }
+
+    #[test]
+    fn test_insert_hiding_vertex() -> Result<(), InsertionError> {
+        let mut rt = Rt::new();
+        for position in [
+            Point2::new(0.0, 0.0),
+            Point2::new(4.0, 0.0),
+            Point2::new(0.0, 4.0),
+            Point2::new(4.0, 4.0),
+        ] {
+            rt.insert(WeightedPoint2::new(position, 0.0))?;
+        }
+        let light = [
+            Point2::new(1.5, 2.5),
+            Point2::new(2.0, 2.0),
+            Point2::new(2.5, 2.5),
+        ]
+        .map(|position| WeightedPoint2::new(position, 0.0));
+        for vertex in light {
+            rt.insert(vertex)?;
+        }
+        assert_eq!(rt.num_vertices(), 7);
+
+        let heavy = WeightedPoint2::new(Point2::new(2.0, 2.2), 5.0);
+        let result = rt.insert_and_report_hidden(heavy)?;
+        let RegularInsertionResult::Inserted {
+            vertex,
+            mut hidden_vertices,
+        } = result
+        else {
+            panic!("Heavy vertex should not be hidden");
+        };
+        rt.sanity_check();
+        assert_eq!(*rt.vertex(vertex).data(), heavy);
+        hidden_vertices.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_eq!(hidden_vertices, light);
+        assert_eq!(rt.num_vertices(), 5);
+
+        // Collinear vertices
+        let mut rt = Rt::new();
+        for x in [0.0, 1.0, 2.0, 3.0] {
+            rt.insert(WeightedPoint2::new(Point2::new(x, 0.0), 0.0))?;
+        }
+        let heavy = WeightedPoint2::new(Point2::new(1.5, 0.0), 1.0);
+        let result = rt.insert_and_report_hidden(heavy)?;
+        assert!(matches!(
+            result,
+            RegularInsertionResult::Inserted { hidden_vertices, .. } if hidden_vertices.len() == 2
+        ));
+        assert_eq!(rt.num_vertices(), 3);
+
+        let beyond = WeightedPoint2::new(Point2::new(-1.0, 0.0), 2.0);
+        let result = rt.insert_and_report_hidden(beyond)?;
+        assert!(matches!(
+            result,
+            RegularInsertionResult::Inserted { hidden_vertices, .. } if hidden_vertices.len() == 1
+        ));
+        rt.insert(WeightedPoint2::new(Point2::new(1.0, 1.0), 0.0))?;
+        rt.sanity_check();
+
+        // Replacing a vertex at the same position
+        let heavier = WeightedPoint2::new(Point2::new(1.5, 0.0), 2.0);
+        let result = rt.insert_and_report_hidden(heavier)?;
+        assert!(matches!(
+            result,
+            RegularInsertionResult::Inserted { hidden_vertices, .. } if hidden_vertices == [heavy]
+        ));
+        rt.sanity_check();
+        Ok(())
+    }
+
+    #[test]
+    fn test_bulk_load_stable() -> Result<(), StableBulkLoadError> {
+        // Only use points that are not hidden by each other
+        let points: Vec<_> = Rt::bulk_load(random_weighted_points(300, SEED2))?
+            .vertices()
+            .map(|vertex| *vertex.data())
+            .rev()
+            .collect();
+
+        let rt = Rt::bulk_load_stable(points.clone())?;
+        rt.sanity_check();
+        assert_eq!(rt.num_vertices(), points.len());
+        for (index, (vertex, point)) in rt.vertices().zip(&points).enumerate() {
+            assert_eq!(vertex.fix().index(), index);
+            assert_eq!(vertex.data(), point);
+        }
+
+        let mut with_duplicate = points.clone();
+        with_duplicate.push(points[10]);
+        assert_eq!(
+            Rt::bulk_load_stable(with_duplicate).err(),
+            Some(StableBulkLoadError::MergedElement {
+                index: points.len(),
+                merged_into: 10
+            })
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_bulk_load_stable_with_hidden_vertices() {
+        let points = vec![
+            WeightedPoint2::new(Point2::new(0.0, 0.0), 1.0),
+            WeightedPoint2::new(Point2::new(2.0, 2.0), -10.0),
+            WeightedPoint2::new(Point2::new(4.0, 0.0), 0.0),
+            WeightedPoint2::new(Point2::new(0.0, 4.0), 0.0),
+            WeightedPoint2::new(Point2::new(4.0, 4.0), 0.0),
+        ];
+
+        assert_eq!(
+            Rt::bulk_load_stable(points.clone()).err(),
+            Some(StableBulkLoadError::MergedElement {
+                index: 1,
+                merged_into: 0
+            })
+        );
+
+        // The hiding element may come after the hidden element
+        let mut points = points;
+        points.push(WeightedPoint2::new(Point2::new(4.0, 0.0), 1.0));
+        points.swap(1, 4);
+        assert_eq!(
+            Rt::bulk_load_stable(points).err(),
+            Some(StableBulkLoadError::MergedElement {
+                index: 2,
+                merged_into: 5
+            })
+        );
+    }
+
+    #[test]
+    fn test_insert_bulk() -> Result<(), InsertionError> {
+        let mut rt = Rt::bulk_load(random_weighted_points(100, SEED))?;
+        let vertices = random_weighted_points(300, SEED2);
+        let handles = rt.insert_bulk(vertices.clone())?;
+
+        rt.sanity_check();
+        assert_eq!(handles.len(), vertices.len());
+        for (handle, vertex) in handles.into_iter().zip(vertices) {
+            let expected = rt.insert_and_report_hidden(vertex)?;
+            assert!(matches!(expected, RegularInsertionResult::Hidden(_)));
+            assert_eq!(rt.insert(vertex)?, handle);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_remove_and_move() -> Result<(), InsertionError> {
+        let points = random_weighted_points(200, SEED);
+        let mut rt =
+            RegularTriangulation::<_, (), (), (), HierarchyHintGenerator<f64>>::bulk_load(points)?;
+
+        for _ in 0..50 {
+            let vertex = rt.fixed_vertices().next().unwrap();
+            let position = rt.vertex(vertex).position();
+            rt.move_vertex(vertex, Point2::new(position.y * 0.5, position.x * 0.9))?;
+            rt.sanity_check();
+        }
+
+        while rt.num_vertices() > 10 {
+            let vertex = rt.fixed_vertices().nth(rt.num_vertices() / 2).unwrap();
+            rt.remove(vertex);
+        }
+        rt.sanity_check();
+        Ok(())
+    }
+
+    #[test]
+    fn test_remove_from_grid() -> Result<(), InsertionError> {
+        // Collinear and cocircular vertices with different weights can become coplanar after
+        // lifting.
+        let mut rt = Rt::new();
+        for x in 0..8 {
+            for y in 0..8 {
+                let weight = ((x * 3 + y * 5) % 4) as f64 * 0.003;
+                rt.insert(WeightedPoint2::new(
+                    Point2::new(x as f64 * 0.1, y as f64 * 0.1),
+                    weight,
+                ))?;
+            }
+        }
+
+        while rt.num_vertices() > 3 {
+            let vertex = rt.fixed_vertices().nth(rt.num_vertices() / 3).unwrap();
+            rt.remove(vertex);
+            rt.sanity_check();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_power_diagram() -> Result<(), InsertionError> {
+        let points = random_weighted_points(100, SEED);
+        let rt = Rt::bulk_load(points)?;
+
+        for face in rt.inner_faces() {
+            let center = face.power_center();
+            let [d0, d1, d2] = face
+                .vertices()
+                .map(|vertex| power_distance(vertex.data(), center));
+            assert!((d0 - d1).abs() < 1e-9);
+            assert!((d0 - d2).abs() < 1e-9);
+        }
+
+        for voronoi_face in rt.voronoi_faces() {
+            let site = voronoi_face.as_delaunay_vertex();
+            for edge in voronoi_face.adjacent_edges() {
+                if let Some(center) = edge.from().power_center() {
+                    let distance = power_distance(site.data(), center);
+                    for other in rt.vertices() {
+                        assert!(power_distance(other.data(), center) >= distance - 1e-9);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_clip_power_cells_to_polygon() -> Result<(), InsertionError> {
+        let points = random_weighted_points(100, SEED);
+        let rt = Rt::bulk_load(points)?;
+
+        let square = [
+            Point2::new(-0.6, -0.6),
+            Point2::new(0.6, -0.6),
+            Point2::new(0.6, 0.6),
+            Point2::new(-0.6, 0.6),
+        ];
+
+        let mut total_area = 0.0;
+        for face in rt.voronoi_faces() {
+            let cell = face.clip_power_cell_to_polygon(&square);
+            let site = face.as_delaunay_vertex();
+            for (index, from) in cell.iter().enumerate() {
+                let to = cell[(index + 1) % cell.len()];
+                total_area += (from.x * to.y - to.x * from.y) * 0.5;
+
+                // Any point of a cell must have the smallest power distance to its site
+                let distance = power_distance(site.data(), *from);
+                for other in rt.vertices() {
+                    assert!(power_distance(other.data(), *from) >= distance - 1e-9);
+                }
+            }
+        }
+        assert!((total_area - 1.2 * 1.2).abs() < 1e-10);
+        Ok(())
+    }
+}
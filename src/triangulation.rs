@@ -1,6 +1,7 @@
 use num_traits::Float;
 
 use crate::delaunay_core::iterators::HullIterator;
+use crate::delaunay_core::math;
 use crate::delaunay_core::InnerOuterMarker;
 use crate::flood_fill_iterator::CircleMetric;
 use crate::flood_fill_iterator::EdgesInShapeIterator;
@@ -9,9 +10,11 @@ use crate::flood_fill_iterator::RectangleMetric;
 use crate::flood_fill_iterator::VerticesInShapeIterator;
 use crate::iterators::*;
 use crate::Barycentric;
+use crate::CloughTocher;
 use crate::HintGenerator;
+use crate::QualityReport;
 use crate::{delaunay_core::Dcel, handles::*};
-use crate::{HasPosition, InsertionError, Point2, TriangulationExt};
+use crate::{HasPosition, HasPositionMut, InsertionError, Point2, TriangulationExt};
 
 use alloc::vec::Vec;
 
@@ -41,6 +44,42 @@ pub enum PositionInTriangulation {
     NoTriangulation,
 }
 
+/// The error type returned by [Triangulation::bulk_load_stable].
+#[derive(Copy, Clone, PartialOrd, Ord, PartialEq, Eq, Debug, Hash)]
+pub enum StableBulkLoadError {
+    /// An input vertex could not be inserted. See [InsertionError] for more details.
+    InsertionError(InsertionError),
+
+    /// An input element would not have created a vertex of its own.
+    ///
+    /// This happens if an element has the same position as another element. Elements of a
+    /// [RegularTriangulation](crate::RegularTriangulation) can also be hidden by other elements.
+    ///
+    /// `index` is the smallest input index of any such element. `merged_into` is the input index
+    /// of the element whose vertex it would have been merged into.
+    MergedElement {
+        /// The input index of the merged element.
+        index: usize,
+        /// The input index of the element that it would have been merged into.
+        merged_into: usize,
+    },
+}
+
+impl From<InsertionError> for StableBulkLoadError {
+    fn from(error: InsertionError) -> Self {
+        StableBulkLoadError::InsertionError(error)
+    }
+}
+
+impl core::fmt::Display for StableBulkLoadError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        <Self as core::fmt::Debug>::fmt(self, f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for StableBulkLoadError {}
+
 /// Defines common operations on triangulations.
 ///
 /// These operations are both available for
@@ -75,6 +114,20 @@ pub trait Triangulation: Default {
     #[doc(hidden)]
     fn handle_legal_edge_split(&mut self, _: [FixedDirectedEdgeHandle; 2]) {}
 
+    /// Returns `true` if `p` lies inside the circumcircle of the counterclockwise triangle
+    /// `v1, v2, v3`. `p` is the vertex opposite of `v3` across the edge `v1 -> v2`. This edge is
+    /// flipped if this method returns `true`.
+    #[doc(hidden)]
+    fn contained_in_circumference(
+        &self,
+        v1: &Self::Vertex,
+        v2: &Self::Vertex,
+        v3: &Self::Vertex,
+        p: &Self::Vertex,
+    ) -> bool {
+        math::contained_in_circumference(v1.position(), v2.position(), v3.position(), p.position())
+    }
+
     #[doc(hidden)]
     fn hint_generator(&self) -> &Self::HintGenerator;
 
@@ -158,6 +211,94 @@ pub trait Triangulation: Default {
         Ok(result)
     }
 
+    /// Creates a new triangulation populated with some vertices by using multiple threads.
+    ///
+    /// The input is split into several vertical strips which are bulk loaded in parallel and then
+    /// stitched together. The result is a Delaunay triangulation of the same vertices as created by
+    /// [bulk_load](Triangulation::bulk_load). It is not necessarily the identical triangulation: If
+    /// four or more vertices are cocircular, the Delaunay triangulation is not unique and both methods
+    /// may pick different edges. The vertex order may differ as well.
+    ///
+    /// This method is only available with the `rayon` feature. Small inputs will be loaded
+    /// sequentially as they don't benefit from parallelization.
+    ///
+    /// Returns an [InsertionError] if any input coordinate is invalid.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use spade::{DelaunayTriangulation, Point2, Triangulation};
+    /// # fn main() -> Result<(), spade::InsertionError> {
+    /// let vertices = (0..1000)
+    ///     .map(|i| Point2::new((i * 37 % 101) as f64, (i * 61 % 103) as f64))
+    ///     .collect();
+    /// let triangulation = DelaunayTriangulation::<Point2<f64>>::bulk_load_parallel(vertices)?;
+    /// assert_eq!(triangulation.num_vertices(), 1000);
+    /// # Ok(()) }
+    /// ```
+    #[cfg(feature = "rayon")]
+    fn bulk_load_parallel(elements: Vec<Self::Vertex>) -> Result<Self, InsertionError>
+    where
+        Self: Send,
+        Self::Vertex: Send,
+    {
+        let mut result: Self = crate::delaunay_core::parallel_bulk_load(elements)?;
+        let hint_generator = Self::HintGenerator::initialize_from_triangulation(&result);
+        *result.hint_generator_mut() = hint_generator;
+        Ok(result)
+    }
+
+    /// Creates a new triangulation populated with some vertices while keeping their order.
+    ///
+    /// This is similar to [bulk_load](Triangulation::bulk_load) with the exception that the
+    /// vertex handle of the i-th input element will have index `i`:
+    ///
+    /// ```
+    /// use spade::{DelaunayTriangulation, Point2, Triangulation};
+    /// # fn main() -> Result<(), spade::StableBulkLoadError> {
+    /// let vertices = vec![Point2::new(0.0, 1.0), Point2::new(4.0, 2.0), Point2::new(1.0, 0.0)];
+    /// let triangulation = DelaunayTriangulation::<_>::bulk_load_stable(vertices.clone())?;
+    ///
+    /// for (index, (vertex, input)) in triangulation.vertices().zip(&vertices).enumerate() {
+    ///     assert_eq!(vertex.fix().index(), index);
+    ///     assert_eq!(vertex.data(), input);
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// This cannot hold if two input elements would share a vertex. Instead of skipping such
+    /// an element, the method fails with [StableBulkLoadError::MergedElement] which reports the
+    /// input indices of both elements:
+    ///
+    /// ```
+    /// use spade::{DelaunayTriangulation, Point2, StableBulkLoadError, Triangulation};
+    /// let vertices = vec![
+    ///     Point2::new(0.0, 1.0),
+    ///     Point2::new(4.0, 2.0),
+    ///     Point2::new(0.0, 1.0), // Duplicate of the first vertex
+    ///     Point2::new(1.0, 0.0),
+    /// ];
+    ///
+    /// let result = DelaunayTriangulation::<_>::bulk_load_stable(vertices);
+    /// assert_eq!(
+    ///     result.err(),
+    ///     Some(StableBulkLoadError::MergedElement { index: 2, merged_into: 0 })
+    /// );
+    /// ```
+    ///
+    /// Returns [StableBulkLoadError::InsertionError] if any input coordinate is invalid.
+    ///
+    /// # Runtime
+    ///
+    /// This method has a run time of `O(n log n)` and is slightly slower than
+    /// [bulk_load](Triangulation::bulk_load).
+    fn bulk_load_stable(elements: Vec<Self::Vertex>) -> Result<Self, StableBulkLoadError> {
+        let mut result: Self = crate::delaunay_core::bulk_load_stable(elements)?;
+        let hint_generator = Self::HintGenerator::initialize_from_triangulation(&result);
+        *result.hint_generator_mut() = hint_generator;
+        Ok(result)
+    }
+
     /// Converts a fixed vertex handle to a reference vertex handle.
     ///
     /// *See also the [handles](crate::handles) module for more information.*
@@ -169,6 +310,9 @@ pub trait Triangulation: Default {
     }
 
     /// Returns a mutable reference to the associated data of a vertex.
+    ///
+    /// The vertex's position must not be changed this way. Use
+    /// [move_vertex](Triangulation::move_vertex) instead.
     fn vertex_data_mut(&mut self, handle: FixedVertexHandle) -> &mut Self::Vertex {
         self.s_mut().vertex_data_mut(handle)
     }
@@ -428,6 +572,9 @@ pub trait Triangulation: Default {
     /// if the hint is close. If the hint is randomized, running time will
     /// be O(sqrt(n)) on average with an O(n) worst case.
     ///
+    /// The returned handle may refer to a different vertex for a
+    /// [RegularTriangulation](crate::RegularTriangulation), see [insert](Triangulation::insert).
+    ///
     /// *See also [insert](Triangulation::insert)*
     fn insert_with_hint(
         &mut self,
@@ -467,6 +614,48 @@ pub trait Triangulation: Default {
         self.remove_and_notify(vertex)
     }
 
+    /// Moves a vertex to a new position.
+    ///
+    /// If the new position lies within the star of the vertex (the polygon formed by all of its
+    /// adjacent faces), the triangulation is repaired with a few local edge flips. This is
+    /// considerably faster than removing and inserting the vertex again, especially if a vertex is
+    /// only moved by a small distance. Otherwise, the vertex is removed and inserted at its new
+    /// position.
+    ///
+    /// The vertex's position is updated with [HasPositionMut::set_position].
+    ///
+    /// Returns `Ok(false)` and leaves the triangulation unchanged if another vertex already
+    /// exists at `new_position`. Returns an error if `new_position` is not a valid coordinate
+    /// (see [validate_coordinate](crate::validate_coordinate)).
+    ///
+    /// # Example
+    /// ```
+    /// # fn main() -> Result<(), spade::InsertionError> {
+    /// use spade::{DelaunayTriangulation, Point2, Triangulation};
+    ///
+    /// let mut triangulation = DelaunayTriangulation::<Point2<f64>>::new();
+    /// let v0 = triangulation.insert(Point2::new(0.0, 0.0))?;
+    /// triangulation.insert(Point2::new(1.0, 0.0))?;
+    /// triangulation.insert(Point2::new(0.0, 1.0))?;
+    ///
+    /// assert!(triangulation.move_vertex(v0, Point2::new(2.0, 2.0))?);
+    /// assert_eq!(triangulation.vertex(v0).position(), Point2::new(2.0, 2.0));
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Handle invalidation
+    /// This method will invalidate all edge and face handles. Vertex handles remain valid.
+    fn move_vertex(
+        &mut self,
+        vertex: FixedVertexHandle,
+        new_position: Point2<<Self::Vertex as HasPosition>::Scalar>,
+    ) -> Result<bool, InsertionError>
+    where
+        Self::Vertex: HasPositionMut,
+    {
+        self.move_vertex_and_notify(vertex, new_position)
+    }
+
     /// Inserts a new vertex into the triangulation.
     ///
     /// This operation runs in O(log(n)) on average when using a tree
@@ -481,6 +670,14 @@ pub trait Triangulation: Default {
     ///
     /// Use [vertex](Triangulation::vertex) to retrieve more information about the inserted vertex.
     ///
+    /// **Note**: A [RegularTriangulation](crate::RegularTriangulation) does not insert a vertex
+    /// that is hidden by the existing vertices. The returned handle refers to a *different* vertex
+    /// in this case: Either to the vertex at the same position or to the vertex whose power cell
+    /// contains the new vertex's position. Inserting into a regular triangulation may also hide
+    /// and remove other vertices, invalidating their handles. Use
+    /// [RegularTriangulation::insert_and_report_hidden](crate::RegularTriangulation::insert_and_report_hidden)
+    /// to detect these cases.
+    ///
     /// # Example
     /// ```
     /// # fn main() -> Result<(), spade::InsertionError> {
@@ -507,6 +704,50 @@ pub trait Triangulation: Default {
         self.insert_with_hint_option(vertex, None)
     }
 
+    /// Inserts a batch of vertices into the triangulation.
+    ///
+    /// Unlike [bulk_load](Triangulation::bulk_load), this method can be used on any existing
+    /// triangulation. The vertices are first sorted spatially along a
+    /// [Hilbert curve](https://en.wikipedia.org/wiki/Hilbert_curve). Each vertex is then inserted
+    /// with the previously inserted vertex as hint. This is considerably faster than
+    /// inserting the vertices in random order.
+    ///
+    /// Returns the handles of all inserted vertices in input order. Just like for
+    /// [insert](Triangulation::insert), a vertex at the same position as an existing vertex
+    /// overwrites it.
+    ///
+    /// Returns an [InsertionError] if any input coordinate is invalid. The triangulation remains
+    /// unchanged in this case.
+    ///
+    /// # Example
+    /// ```
+    /// # fn main() -> Result<(), spade::InsertionError> {
+    /// use spade::{DelaunayTriangulation, Point2, Triangulation};
+    ///
+    /// let mut triangulation: DelaunayTriangulation<_> =
+    ///     DelaunayTriangulation::bulk_load(vec![Point2::new(0.0, 0.0), Point2::new(1.0, 0.0)])?;
+    ///
+    /// let vertices = vec![Point2::new(0.0, 1.0), Point2::new(1.0, 1.0), Point2::new(0.5, 0.5)];
+    /// let handles = triangulation.insert_bulk(vertices.clone())?;
+    ///
+    /// assert_eq!(triangulation.num_vertices(), 5);
+    /// for (handle, vertex) in handles.iter().zip(vertices) {
+    ///     assert_eq!(triangulation.vertex(*handle).position(), vertex);
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Runtime
+    ///
+    /// This method runs in `O(n log n)` for sorting the vertices and performs each insertion in
+    /// `O(1)` on average, assuming that the vertices are evenly distributed.
+    fn insert_bulk(
+        &mut self,
+        vertices: Vec<Self::Vertex>,
+    ) -> Result<Vec<FixedVertexHandle>, InsertionError> {
+        crate::delaunay_core::insert_bulk(self, vertices)
+    }
+
     /// An iterator visiting all undirected edges.
     ///
     /// The iterator type is [FixedUndirectedEdgeHandle].
@@ -702,6 +943,203 @@ where
         VerticesInShapeIterator::new(FloodFillIterator::new(self, distance_metric, center))
     }
 
+    /// Performs Lloyd relaxation on this triangulation.
+    ///
+    /// Each iteration moves every vertex to the centroid of its Voronoi cell. Cells are clipped to
+    /// the convex polygon `bounds` (see [VoronoiFace::clip_to_polygon]). Repeating this results in
+    /// a more evenly distributed set of vertices.
+    ///
+    /// Vertices are moved by removing them and inserting a new vertex created from their target
+    /// position. Any other data stored in a moved vertex is lost. Vertices are not moved if their
+    /// cell lies outside of `bounds` or if their target position is already occupied. Vertices
+    /// adjacent to a constraint edge of a [crate::ConstrainedDelaunayTriangulation] are never
+    /// moved.
+    ///
+    /// The relaxation stops early if no vertex was moved during an iteration.
+    ///
+    /// This method always uses unweighted Voronoi cells. It is not meant to be used with a
+    /// [RegularTriangulation](crate::RegularTriangulation): Vertex weights are ignored when
+    /// calculating the cells and moved vertices receive the weight of their `From<Point2>`
+    /// conversion.
+    ///
+    /// # Handle invalidation
+    /// This method will invalidate all vertex, edge and face handles.
+    ///
+    /// # Example
+    /// ```
+    /// # fn main() -> Result<(), spade::InsertionError> {
+    /// use spade::{DelaunayTriangulation, FloatTriangulation, Point2, Triangulation};
+    ///
+    /// let mut triangulation = DelaunayTriangulation::<Point2<f64>>::bulk_load(vec![
+    ///     Point2::new(0.1, 0.1),
+    ///     Point2::new(0.2, 0.1),
+    ///     Point2::new(0.1, 0.2),
+    ///     Point2::new(0.2, 0.25),
+    /// ])?;
+    ///
+    /// let bounds = [
+    ///     Point2::new(0.0, 0.0),
+    ///     Point2::new(1.0, 0.0),
+    ///     Point2::new(1.0, 1.0),
+    ///     Point2::new(0.0, 1.0),
+    /// ];
+    /// triangulation.lloyd_relaxation(10, &bounds);
+    ///
+    /// assert_eq!(triangulation.num_vertices(), 4);
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// *See also [lloyd_relaxation_with_fixed_vertices](FloatTriangulation::lloyd_relaxation_with_fixed_vertices)*
+    fn lloyd_relaxation(
+        &mut self,
+        iterations: usize,
+        bounds: &[Point2<<Self::Vertex as HasPosition>::Scalar>],
+    ) where
+        Self::Vertex: From<Point2<<Self::Vertex as HasPosition>::Scalar>>,
+    {
+        self.lloyd_relaxation_with_fixed_vertices(iterations, bounds, |_| false)
+    }
+
+    /// Performs Lloyd relaxation while keeping some vertices in place.
+    ///
+    /// `is_fixed` is called for every vertex once per iteration. Vertices for which it returns
+    /// `true` are not moved.
+    ///
+    /// Refer to [lloyd_relaxation](FloatTriangulation::lloyd_relaxation) for more information.
+    ///
+    /// # Example
+    /// ```
+    /// # fn main() -> Result<(), spade::InsertionError> {
+    /// use spade::{DelaunayTriangulation, FloatTriangulation, Point2, Triangulation};
+    ///
+    /// let mut triangulation = DelaunayTriangulation::<Point2<f64>>::bulk_load(vec![
+    ///     Point2::new(0.0, 0.0),
+    ///     Point2::new(0.1, 0.1),
+    ///     Point2::new(0.2, 0.1),
+    /// ])?;
+    ///
+    /// let bounds = [
+    ///     Point2::new(0.0, 0.0),
+    ///     Point2::new(1.0, 0.0),
+    ///     Point2::new(1.0, 1.0),
+    ///     Point2::new(0.0, 1.0),
+    /// ];
+    ///
+    /// // Keep the origin in place
+    /// triangulation.lloyd_relaxation_with_fixed_vertices(10, &bounds, |vertex| {
+    ///     vertex.position() == Point2::new(0.0, 0.0)
+    /// });
+    ///
+    /// assert!(triangulation.locate_vertex(Point2::new(0.0, 0.0)).is_some());
+    /// # Ok(()) }
+    /// ```
+    fn lloyd_relaxation_with_fixed_vertices<P>(
+        &mut self,
+        iterations: usize,
+        bounds: &[Point2<<Self::Vertex as HasPosition>::Scalar>],
+        mut is_fixed: P,
+    ) where
+        Self::Vertex: From<Point2<<Self::Vertex as HasPosition>::Scalar>>,
+        P: FnMut(
+            VertexHandle<Self::Vertex, Self::DirectedEdge, Self::UndirectedEdge, Self::Face>,
+        ) -> bool,
+    {
+        for _ in 0..iterations {
+            let mut targets = Vec::new();
+            for face in self.voronoi_faces() {
+                let vertex = face.as_delaunay_vertex();
+                let is_constraint_vertex = vertex
+                    .out_edges()
+                    .any(|edge| self.is_defined_legal(edge.fix().as_undirected()));
+
+                if is_constraint_vertex || is_fixed(vertex) {
+                    continue;
+                }
+
+                let target = match math::polygon_centroid(&face.clip_to_polygon(bounds)) {
+                    Some(target) => target,
+                    None => continue,
+                };
+
+                if target != vertex.position()
+                    && math::validate_coordinate(target.x).is_ok()
+                    && math::validate_coordinate(target.y).is_ok()
+                {
+                    targets.push((vertex.fix(), target));
+                }
+            }
+
+            let mut num_moved_vertices = 0;
+
+            // Removing a vertex moves the last vertex into its slot and inserting appends a new
+            // vertex. Moving the vertices in descending order keeps all remaining handles valid.
+            for (vertex, target) in targets.into_iter().rev() {
+                let is_occupied = match self.locate(target) {
+                    PositionInTriangulation::OnVertex(_) => true,
+                    PositionInTriangulation::OnEdge(edge) => {
+                        // Inserting a vertex on a constraint edge would split it
+                        self.is_defined_legal(edge.as_undirected())
+                    }
+                    _ => false,
+                };
+
+                if is_occupied {
+                    continue;
+                }
+
+                self.remove(vertex);
+                self.insert(target.into())
+                    .expect("Target position was validated");
+                num_moved_vertices += 1;
+            }
+
+            if num_moved_vertices == 0 {
+                break;
+            }
+        }
+    }
+
+    /// Returns a report about the quality of this triangulation's mesh.
+    ///
+    /// The report contains the distribution of angles, aspect ratios, radius to edge ratios and
+    /// areas of all inner faces. Refer to [QualityReport] for more information.
+    ///
+    /// # Example
+    /// ```
+    /// # fn main() -> Result<(), spade::InsertionError> {
+    /// use spade::{DelaunayTriangulation, FloatTriangulation, Point2, Triangulation};
+    ///
+    /// let triangulation = DelaunayTriangulation::<Point2<f64>>::bulk_load(vec![
+    ///     Point2::new(0.0, 0.0),
+    ///     Point2::new(1.0, 0.0),
+    ///     Point2::new(1.0, 1.0),
+    ///     Point2::new(0.0, 1.0),
+    /// ])?;
+    ///
+    /// let report = triangulation.quality_report();
+    /// assert_eq!(report.num_faces, 2);
+    /// assert!((report.min_angle.min.to_degrees() - 45.0).abs() < 1e-10);
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// *See also [quality_report_excluding](FloatTriangulation::quality_report_excluding)*
+    fn quality_report(&self) -> QualityReport<<Self::Vertex as HasPosition>::Scalar> {
+        crate::delaunay_core::quality_report(self, &[])
+    }
+
+    /// Returns a report about the quality of this triangulation's mesh, ignoring some faces.
+    ///
+    /// This is useful in combination with [RefinementResult::excluded_faces](crate::RefinementResult::excluded_faces)
+    /// to only inspect the faces within the constrained area of a refined CDT.
+    ///
+    /// *See also [quality_report](FloatTriangulation::quality_report)*
+    fn quality_report_excluding(
+        &self,
+        excluded_faces: &[FixedFaceHandle<InnerTag>],
+    ) -> QualityReport<<Self::Vertex as HasPosition>::Scalar> {
+        crate::delaunay_core::quality_report(self, excluded_faces)
+    }
+
     /// Used for barycentric interpolation on this triangulation. Refer to the documentation of
     /// [Barycentric] and [crate::NaturalNeighbor] for more information.
     ///
@@ -710,6 +1148,12 @@ where
     fn barycentric(&self) -> Barycentric<Self> {
         Barycentric::new(self)
     }
+
+    /// Used for Clough-Tocher interpolation on this triangulation. Refer to the documentation of
+    /// [CloughTocher] for more information.
+    fn clough_tocher(&self) -> CloughTocher<'_, Self> {
+        CloughTocher::new(self)
+    }
 }
 
 impl<T> FloatTriangulation for T